@@ -0,0 +1,51 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const TLD_LIST: &str = "data/tlds-alpha-by-domain.txt";
+const TLD_LIST_URL: &str = "https://data.iana.org/TLD/tlds-alpha-by-domain.txt";
+const FETCH_TLDS_ENV: &str = "RUST_USER_INPUT_VALIDATION_FETCH_TLDS";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", TLD_LIST);
+    println!("cargo:rerun-if-env-changed={}", FETCH_TLDS_ENV);
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let list = fetch_tld_list()
+        .unwrap_or_else(|| fs::read_to_string(TLD_LIST).expect("bundled TLD list is readable"));
+    fs::write(Path::new(&out_dir).join("tlds-alpha-by-domain.txt"), &list)
+        .expect("OUT_DIR is writable");
+
+    let mut tlds: Vec<String> = list
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_ascii_lowercase)
+        .collect();
+    tlds.sort();
+    tlds.dedup();
+
+    // One packed, newline-separated string instead of an array of `&str`
+    // halves the table size; lookups binary-search its lines.
+    let source = format!("static IANA_TLDS: &str = {:?};\n", tlds.join("\n"));
+    fs::write(Path::new(&out_dir).join("tlds.rs"), source).expect("OUT_DIR is writable");
+}
+
+// Network access is opt-in so offline and reproducible builds keep using the bundled list.
+fn fetch_tld_list() -> Option<String> {
+    env::var_os(FETCH_TLDS_ENV)?;
+
+    let output = Command::new("curl")
+        .args(["--silent", "--fail", "--location", TLD_LIST_URL])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        println!(
+            "cargo:warning=failed to fetch {}, using bundled list",
+            TLD_LIST_URL
+        );
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
@@ -0,0 +1,47 @@
+use rust_user_input_validation::{InputSchema, ValidatorRegistry};
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("Usage: {} <schema.json> <data.json>", args[0]);
+        process::exit(2);
+    }
+
+    match run(&args[1], &args[2]) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            process::exit(2);
+        }
+    }
+}
+
+fn run(schema_path: &str, data_path: &str) -> Result<bool, Box<dyn Error>> {
+    let schemas: Vec<InputSchema> = serde_json::from_str(&fs::read_to_string(schema_path)?)?;
+    let data: HashMap<String, String> = serde_json::from_str(&fs::read_to_string(data_path)?)?;
+    let registry = ValidatorRegistry::standard();
+
+    let mut all_valid = true;
+    for schema in &schemas {
+        let validator = schema.to_validator(&registry)?;
+
+        let status = match data.get(&schema.field) {
+            Some(value) if validator.validate(value) => "ok",
+            Some(_) => "invalid",
+            None => "missing",
+        };
+        if status != "ok" {
+            all_valid = false;
+        }
+
+        println!("{}: {}", schema.field, status);
+    }
+
+    Ok(all_valid)
+}
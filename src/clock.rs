@@ -0,0 +1,125 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Self { year, month, day }
+    }
+
+    pub fn parse_iso(date: &str) -> Option<Self> {
+        let mut parts = date.splitn(3, '-');
+        let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+        let is_digits =
+            |part: &str, len: usize| part.len() == len && part.chars().all(|c| c.is_ascii_digit());
+
+        if !is_digits(year, 4) || !is_digits(month, 2) || !is_digits(day, 2) {
+            return None;
+        }
+
+        let date = Self::new(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?);
+        let is_valid = (1..=12).contains(&date.month)
+            && (1..=days_in_month(date.year, date.month)).contains(&date.day);
+        is_valid.then_some(date)
+    }
+
+    #[cfg(feature = "std")]
+    fn from_days_since_epoch(days: i64) -> Self {
+        // Civil-from-days conversion for the proleptic Gregorian calendar.
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self::new(year as i32, month as u32, day as u32)
+    }
+}
+
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+pub trait Clock {
+    fn today(&self) -> Date;
+}
+
+#[cfg(feature = "std")]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn today(&self) -> Date {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() as i64)
+            .unwrap_or(0);
+        Date::from_days_since_epoch(seconds.div_euclid(86_400))
+    }
+}
+
+pub struct FixedClock(pub Date);
+
+impl Clock for FixedClock {
+    fn today(&self) -> Date {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_date_from_days_since_epoch() {
+        assert_eq!(Date::from_days_since_epoch(0), Date::new(1970, 1, 1));
+        assert_eq!(Date::from_days_since_epoch(11_016), Date::new(2000, 2, 29));
+        assert_eq!(Date::from_days_since_epoch(19_723), Date::new(2024, 1, 1));
+        assert_eq!(Date::from_days_since_epoch(-1), Date::new(1969, 12, 31));
+    }
+
+    #[test]
+    fn test_date_parse_iso() {
+        assert_eq!(Date::parse_iso("2024-02-29"), Some(Date::new(2024, 2, 29)));
+        assert_eq!(Date::parse_iso("2000-02-29"), Some(Date::new(2000, 2, 29)));
+        assert_eq!(Date::parse_iso("2023-02-29"), None);
+        assert_eq!(Date::parse_iso("1900-02-29"), None);
+        assert_eq!(Date::parse_iso("2024-04-31"), None);
+        assert_eq!(Date::parse_iso("2024-13-01"), None);
+        assert_eq!(Date::parse_iso("2024-1-01"), None);
+        assert_eq!(Date::parse_iso("2024-01-01T00:00"), None);
+        assert_eq!(Date::parse_iso("20240101"), None);
+    }
+
+    #[test]
+    fn test_fixed_clock() {
+        let clock = FixedClock(Date::new(2024, 6, 15));
+        assert_eq!(clock.today(), Date::new(2024, 6, 15));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_system_clock() {
+        assert!(SystemClock.today() > Date::new(2024, 1, 1));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::validators::parse_rgb;
+
+pub fn validate_contrast_ratio(foreground: &str, background: &str, min_ratio: f64) -> bool {
+    contrast_ratio(foreground, background).is_some_and(|ratio| ratio >= min_ratio)
+}
+
+pub fn validate_wcag_aa(foreground: &str, background: &str) -> bool {
+    validate_contrast_ratio(foreground, background, 4.5)
+}
+
+pub fn validate_wcag_aaa(foreground: &str, background: &str) -> bool {
+    validate_contrast_ratio(foreground, background, 7.0)
+}
+
+pub fn contrast_ratio(foreground: &str, background: &str) -> Option<f64> {
+    let foreground = relative_luminance(parse_rgb(foreground)?);
+    let background = relative_luminance(parse_rgb(background)?);
+    let (lighter, darker) = if foreground >= background {
+        (foreground, background)
+    } else {
+        (background, foreground)
+    };
+
+    Some((lighter + 0.05) / (darker + 0.05))
+}
+
+fn relative_luminance(rgb: [u8; 3]) -> f64 {
+    let [r, g, b] = rgb.map(|channel| {
+        let channel = f64::from(channel) / 255.0;
+        if channel <= 0.04045 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    });
+
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contrast_ratio() {
+        let ratio = contrast_ratio("#000000", "#ffffff").unwrap();
+        assert!((ratio - 21.0).abs() < 1e-9);
+        assert_eq!(contrast_ratio("#fff", "rgb(255, 255, 255)"), Some(1.0));
+        assert_eq!(
+            contrast_ratio("#336699", "#ffffff"),
+            contrast_ratio("#ffffff", "#336699")
+        );
+        assert_eq!(contrast_ratio("notacolor", "#ffffff"), None);
+    }
+
+    #[test]
+    fn test_validate_contrast_ratio() {
+        assert!(validate_contrast_ratio("#000", "#fff", 21.0));
+        assert!(validate_contrast_ratio("0,0,255", "255,255,255", 8.0));
+        assert!(!validate_contrast_ratio("#777777", "#888888", 1.5));
+        assert!(!validate_contrast_ratio("#zzz", "#fff", 1.0));
+    }
+
+    #[test]
+    fn test_validate_wcag_aa() {
+        assert!(validate_wcag_aa("#767676", "#ffffff"));
+        assert!(!validate_wcag_aa("#777777", "#ffffff"));
+    }
+
+    #[test]
+    fn test_validate_wcag_aaa() {
+        assert!(validate_wcag_aaa("#595959", "#ffffff"));
+        assert!(!validate_wcag_aaa("#767676", "#ffffff"));
+    }
+}
@@ -0,0 +1,349 @@
+use crate::{read_input, Validator};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+use std::io::{self, IsTerminal, Write};
+use std::str::FromStr;
+
+/// Like [`read_input`], with Tab completion when stdin is a terminal.
+///
+/// Returns `None` at end of input or when the user cancels with Ctrl-C or Ctrl-D.
+pub fn read_input_with_completion<T>(
+    prompt: &str,
+    completions: &[&str],
+    validator: &Validator,
+) -> Option<T>
+where
+    T: FromStr,
+    T::Err: core::fmt::Debug,
+{
+    if !io::stdin().is_terminal() {
+        return read_input(prompt, validator);
+    }
+
+    loop {
+        println!("{}", prompt);
+
+        let line = read_line_with_completion(completions).expect("Failed to read input")?;
+        let input = line.trim();
+
+        if let Ok(value) = input.parse::<T>() {
+            if validator.validate(input) {
+                return Some(value);
+            } else {
+                println!("Invalid input, please try again.");
+            }
+        } else {
+            println!("Failed to convert value, please try again.");
+        }
+    }
+}
+
+pub fn matching_completions<'a>(input: &str, completions: &[&'a str]) -> Vec<&'a str> {
+    completions
+        .iter()
+        .copied()
+        .filter(|completion| completion.starts_with(input))
+        .collect()
+}
+
+fn longest_common_prefix<'a>(candidates: &[&'a str]) -> &'a str {
+    let first = match candidates.first() {
+        Some(first) => *first,
+        None => return "",
+    };
+
+    let mut end = first.len();
+    for candidate in &candidates[1..] {
+        end = first[..end]
+            .char_indices()
+            .zip(candidate.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(end.min(candidate.len()), |((index, _), _)| index);
+    }
+    &first[..end]
+}
+
+#[derive(Debug, PartialEq)]
+enum LineEvent<'a> {
+    Edited,
+    Completions(Vec<&'a str>),
+    Submitted,
+    Cancelled,
+    Ignored,
+}
+
+#[derive(Default)]
+struct LineBuffer {
+    text: String,
+    cursor: usize,
+}
+
+impl LineBuffer {
+    fn handle_key<'a>(&mut self, key: KeyEvent, completions: &[&'a str]) -> LineEvent<'a> {
+        if key.kind == KeyEventKind::Release {
+            return LineEvent::Ignored;
+        }
+
+        match key.code {
+            KeyCode::Char('c') | KeyCode::Char('d')
+                if key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                LineEvent::Cancelled
+            }
+            KeyCode::Char(_) if is_control_chord(key.modifiers) => LineEvent::Ignored,
+            KeyCode::Char(c) => {
+                self.text.insert(self.cursor, c);
+                self.cursor += c.len_utf8();
+                LineEvent::Edited
+            }
+            KeyCode::Backspace => match self.text[..self.cursor].chars().next_back() {
+                Some(c) => {
+                    self.cursor -= c.len_utf8();
+                    self.text.remove(self.cursor);
+                    LineEvent::Edited
+                }
+                None => LineEvent::Ignored,
+            },
+            KeyCode::Delete if self.cursor < self.text.len() => {
+                self.text.remove(self.cursor);
+                LineEvent::Edited
+            }
+            KeyCode::Left => match self.text[..self.cursor].chars().next_back() {
+                Some(c) => {
+                    self.cursor -= c.len_utf8();
+                    LineEvent::Edited
+                }
+                None => LineEvent::Ignored,
+            },
+            KeyCode::Right => match self.text[self.cursor..].chars().next() {
+                Some(c) => {
+                    self.cursor += c.len_utf8();
+                    LineEvent::Edited
+                }
+                None => LineEvent::Ignored,
+            },
+            KeyCode::Home => {
+                self.cursor = 0;
+                LineEvent::Edited
+            }
+            KeyCode::End => {
+                self.cursor = self.text.len();
+                LineEvent::Edited
+            }
+            KeyCode::Tab => self.complete(completions),
+            KeyCode::Enter => LineEvent::Submitted,
+            _ => LineEvent::Ignored,
+        }
+    }
+
+    fn complete<'a>(&mut self, completions: &[&'a str]) -> LineEvent<'a> {
+        let matches = matching_completions(&self.text, completions);
+        let prefix = longest_common_prefix(&matches);
+
+        if prefix.len() > self.text.len() {
+            self.text = prefix.to_string();
+            self.cursor = self.text.len();
+        }
+
+        match matches.len() {
+            0 => LineEvent::Ignored,
+            1 => LineEvent::Edited,
+            _ => LineEvent::Completions(matches),
+        }
+    }
+}
+
+// Ctrl+letter chords have no binding here beyond cancel. AltGr arrives as
+// Ctrl+Alt on Windows and must still insert its character.
+fn is_control_chord(modifiers: KeyModifiers) -> bool {
+    modifiers.contains(KeyModifiers::CONTROL) && !modifiers.contains(KeyModifiers::ALT)
+}
+
+struct RawModeGuard;
+
+impl RawModeGuard {
+    fn enable() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+// Returns `Ok(None)` when the user cancels with Ctrl-C or Ctrl-D.
+fn read_line_with_completion(completions: &[&str]) -> io::Result<Option<String>> {
+    let mut stdout = io::stdout();
+    let mut line = LineBuffer::default();
+    let _raw_mode = RawModeGuard::enable()?;
+
+    loop {
+        let key = match event::read() {
+            Ok(Event::Key(key)) => key,
+            Ok(_) => continue,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => return Ok(None),
+            Err(error) => return Err(error),
+        };
+
+        match line.handle_key(key, completions) {
+            LineEvent::Edited => redraw(&mut stdout, &line)?,
+            LineEvent::Completions(matches) => {
+                write!(stdout, "\r\n{}\r\n", matches.join("  "))?;
+                redraw(&mut stdout, &line)?;
+            }
+            LineEvent::Submitted => {
+                write!(stdout, "\r\n")?;
+                stdout.flush()?;
+                return Ok(Some(line.text));
+            }
+            LineEvent::Cancelled => {
+                write!(stdout, "\r\n")?;
+                stdout.flush()?;
+                return Ok(None);
+            }
+            LineEvent::Ignored => {}
+        }
+    }
+}
+
+fn redraw(stdout: &mut io::Stdout, line: &LineBuffer) -> io::Result<()> {
+    let cursor_column = line.text[..line.cursor].chars().count();
+    write!(stdout, "\r\x1b[2K{}\r", line.text)?;
+    if cursor_column > 0 {
+        write!(stdout, "\x1b[{}C", cursor_column)?;
+    }
+    stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPLETIONS: [&str; 4] = ["apple", "apricot", "banana", "blueberry"];
+
+    fn press(line: &mut LineBuffer, code: KeyCode) -> LineEvent<'static> {
+        line.handle_key(KeyEvent::new(code, KeyModifiers::NONE), &COMPLETIONS)
+    }
+
+    fn type_text(line: &mut LineBuffer, text: &str) {
+        for c in text.chars() {
+            press(line, KeyCode::Char(c));
+        }
+    }
+
+    #[test]
+    fn test_matching_completions() {
+        assert_eq!(
+            matching_completions("ap", &COMPLETIONS),
+            ["apple", "apricot"]
+        );
+        assert_eq!(matching_completions("ban", &COMPLETIONS), ["banana"]);
+        assert_eq!(matching_completions("", &COMPLETIONS), COMPLETIONS);
+        assert!(matching_completions("cherry", &COMPLETIONS).is_empty());
+    }
+
+    #[test]
+    fn test_longest_common_prefix() {
+        assert_eq!(longest_common_prefix(&["apple", "apricot"]), "ap");
+        assert_eq!(longest_common_prefix(&["banana"]), "banana");
+        assert_eq!(longest_common_prefix(&["blue", "blueberry"]), "blue");
+        assert_eq!(longest_common_prefix(&["żółw", "żółty"]), "żół");
+        assert_eq!(longest_common_prefix(&[]), "");
+    }
+
+    #[test]
+    fn test_tab_completes_unique_match() {
+        let mut line = LineBuffer::default();
+        type_text(&mut line, "ban");
+        assert_eq!(press(&mut line, KeyCode::Tab), LineEvent::Edited);
+        assert_eq!(line.text, "banana");
+        assert_eq!(line.cursor, line.text.len());
+    }
+
+    #[test]
+    fn test_tab_lists_ambiguous_matches() {
+        let mut line = LineBuffer::default();
+        type_text(&mut line, "b");
+        assert_eq!(
+            press(&mut line, KeyCode::Tab),
+            LineEvent::Completions(vec!["banana", "blueberry"])
+        );
+        assert_eq!(line.text, "b");
+
+        type_text(&mut line, "l");
+        press(&mut line, KeyCode::Tab);
+        assert_eq!(line.text, "blueberry");
+    }
+
+    #[test]
+    fn test_tab_without_matches() {
+        let mut line = LineBuffer::default();
+        type_text(&mut line, "cherry");
+        assert_eq!(press(&mut line, KeyCode::Tab), LineEvent::Ignored);
+        assert_eq!(line.text, "cherry");
+    }
+
+    #[test]
+    fn test_line_editing() {
+        let mut line = LineBuffer::default();
+        type_text(&mut line, "appel");
+        press(&mut line, KeyCode::Backspace);
+        press(&mut line, KeyCode::Left);
+        press(&mut line, KeyCode::Char('l'));
+        assert_eq!(line.text, "apple");
+        press(&mut line, KeyCode::End);
+        press(&mut line, KeyCode::Char('s'));
+        assert_eq!(line.text, "apples");
+        press(&mut line, KeyCode::Home);
+        press(&mut line, KeyCode::Delete);
+        assert_eq!(line.text, "pples");
+        assert_eq!(press(&mut line, KeyCode::Enter), LineEvent::Submitted);
+    }
+
+    #[test]
+    fn test_ctrl_c_cancels() {
+        let mut line = LineBuffer::default();
+        let event = line.handle_key(
+            KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            &COMPLETIONS,
+        );
+        assert_eq!(event, LineEvent::Cancelled);
+        assert!(line.text.is_empty());
+    }
+
+    #[test]
+    fn test_control_chords_are_ignored() {
+        let mut line = LineBuffer::default();
+        type_text(&mut line, "ab");
+        let event = line.handle_key(
+            KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
+            &COMPLETIONS,
+        );
+        assert_eq!(event, LineEvent::Ignored);
+        assert_eq!(line.text, "ab");
+
+        let event = line.handle_key(
+            KeyEvent::new(
+                KeyCode::Char('@'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT,
+            ),
+            &COMPLETIONS,
+        );
+        assert_eq!(event, LineEvent::Edited);
+        assert_eq!(line.text, "ab@");
+    }
+
+    #[test]
+    fn test_shifted_characters_are_inserted() {
+        let mut line = LineBuffer::default();
+        let event = line.handle_key(
+            KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT),
+            &COMPLETIONS,
+        );
+        assert_eq!(event, LineEvent::Edited);
+        assert_eq!(line.text, "A");
+    }
+}
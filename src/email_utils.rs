@@ -0,0 +1,45 @@
+use alloc::format;
+use alloc::string::String;
+
+pub fn has_plus_tag(email: &str) -> bool {
+    email
+        .rsplit_once('@')
+        .is_some_and(|(local, _)| local.contains('+'))
+}
+
+pub fn strip_plus_tag(email: &str) -> String {
+    match email.rsplit_once('@') {
+        Some((local, domain)) => match local.split_once('+') {
+            Some((user, _)) => format!("{}@{}", user, domain),
+            None => email.into(),
+        },
+        None => email.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_plus_tag() {
+        assert!(has_plus_tag("user+newsletter@example.com"));
+        assert!(has_plus_tag("user+@example.com"));
+        assert!(has_plus_tag("user+a+b@example.com"));
+        assert!(!has_plus_tag("user@example.com"));
+        assert!(!has_plus_tag("user@exam+ple.com"));
+        assert!(!has_plus_tag("user+newsletter"));
+    }
+
+    #[test]
+    fn test_strip_plus_tag() {
+        assert_eq!(
+            strip_plus_tag("user+newsletter@example.com"),
+            "user@example.com"
+        );
+        assert_eq!(strip_plus_tag("user+@example.com"), "user@example.com");
+        assert_eq!(strip_plus_tag("user+a+b@example.com"), "user@example.com");
+        assert_eq!(strip_plus_tag("user@example.com"), "user@example.com");
+        assert_eq!(strip_plus_tag("user+newsletter"), "user+newsletter");
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{read_input_from, Validator};
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+#[derive(Default)]
+pub struct FormBuilder {
+    fields: Vec<(String, Validator)>,
+}
+
+impl FormBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field(mut self, name: impl Into<String>, validator: Validator) -> Self {
+        self.fields.push((name.into(), validator));
+        self
+    }
+
+    pub fn build(self) -> Form {
+        Form {
+            fields: self.fields,
+        }
+    }
+}
+
+pub struct Form {
+    fields: Vec<(String, Validator)>,
+}
+
+impl Form {
+    pub fn field_names(&self) -> Vec<&str> {
+        self.fields.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    pub fn read_all(&self) -> Option<HashMap<String, String>> {
+        self.read_all_from(&mut io::stdin().lock())
+    }
+
+    /// Returns `None` if the reader runs out before every field is accepted.
+    pub fn read_all_from<R: BufRead>(&self, reader: &mut R) -> Option<HashMap<String, String>> {
+        self.fields
+            .iter()
+            .map(|(name, validator)| {
+                let prompt = format!("Enter {}:", name);
+                let value: String = read_input_from(&prompt, validator, reader)?;
+                Some((name.clone(), value))
+            })
+            .collect()
+    }
+
+    pub fn validate_all(&self, data: &HashMap<&str, &str>) -> ValidationReport {
+        let results = self
+            .fields
+            .iter()
+            .map(|(name, validator)| {
+                let value = data.get(name.as_str()).copied().unwrap_or("");
+                (name.clone(), validator.validate(value))
+            })
+            .collect();
+
+        ValidationReport { results }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ValidationReport {
+    results: Vec<(String, bool)>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.results.iter().all(|(_, valid)| *valid)
+    }
+
+    pub fn is_field_valid(&self, field: &str) -> Option<bool> {
+        self.results
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, valid)| *valid)
+    }
+
+    pub fn invalid_fields(&self) -> Vec<&str> {
+        self.results
+            .iter()
+            .filter(|(_, valid)| !valid)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+}
@@ -0,0 +1,622 @@
+use crate::ValidationError;
+#[cfg(feature = "std")]
+use alloc::format;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+pub trait FormatterFactory {
+    fn format(&self, error: &ValidationError, locale: &str) -> String;
+}
+
+// English message for each rule in `ValidatorRegistry::standard()`, plus the
+// `required` alias. Sorted by rule name for binary search.
+const RULE_MESSAGES: [(&str, &str); 147] = [
+    ("not_empty", "{field} is required"),
+    ("required", "{field} is required"),
+    (
+        "validate_aba_routing",
+        "{field} must be a valid ABA routing number",
+    ),
+    (
+        "validate_android_package_name",
+        "{field} must be a valid Android package name",
+    ),
+    (
+        "validate_android_package_name_play_store",
+        "{field} must be an Android package name accepted by Google Play",
+    ),
+    (
+        "validate_any_css_color",
+        "{field} must be a valid CSS color",
+    ),
+    (
+        "validate_apple_bundle_id",
+        "{field} must be a valid Apple bundle identifier",
+    ),
+    (
+        "validate_apple_bundle_id_strict",
+        "{field} must be a valid reverse-DNS Apple bundle identifier",
+    ),
+    ("validate_aws_arn", "{field} must be a valid AWS ARN"),
+    (
+        "validate_azure_resource_group_name",
+        "{field} must be a valid Azure resource group name",
+    ),
+    (
+        "validate_azure_storage_account_name",
+        "{field} must be a valid Azure storage account name",
+    ),
+    (
+        "validate_azure_subscription_id",
+        "{field} must be a valid Azure subscription ID",
+    ),
+    ("validate_base64", "{field} must be valid Base64"),
+    ("validate_bic", "{field} must be a valid BIC"),
+    (
+        "validate_bic_with_country_check",
+        "{field} must be a valid BIC with a known country code",
+    ),
+    ("validate_bsn", "{field} must be a valid Dutch BSN"),
+    (
+        "validate_canadian_sin",
+        "{field} must be a valid Canadian SIN",
+    ),
+    (
+        "validate_conda_build_string",
+        "{field} must be a valid conda build string",
+    ),
+    (
+        "validate_conda_package_name",
+        "{field} must be a valid conda package name",
+    ),
+    ("validate_crate_name", "{field} must be a valid crate name"),
+    (
+        "validate_crate_name_strict",
+        "{field} must be a valid crate name that does not imitate a well-known crate",
+    ),
+    (
+        "validate_credit_card_expiry",
+        "{field} must be a card expiry date that has not passed",
+    ),
+    (
+        "validate_credit_card_expiry_future",
+        "{field} must be a card expiry date after the current month",
+    ),
+    (
+        "validate_cron_expression",
+        "{field} must be a valid cron expression",
+    ),
+    (
+        "validate_csp_directive",
+        "{field} must be a valid Content-Security-Policy directive",
+    ),
+    (
+        "validate_csp_policy",
+        "{field} must be a valid Content-Security-Policy",
+    ),
+    ("validate_cve_id", "{field} must be a valid CVE identifier"),
+    ("validate_data_uri", "{field} must be a valid data URI"),
+    (
+        "validate_docker_image_name",
+        "{field} must be a valid Docker image name",
+    ),
+    ("validate_docker_tag", "{field} must be a valid Docker tag"),
+    (
+        "validate_docker_tag_no_latest",
+        "{field} must be a valid Docker tag other than latest",
+    ),
+    ("validate_domain", "{field} must be a valid domain name"),
+    ("validate_duns", "{field} must be a 9-digit D-U-N-S number"),
+    (
+        "validate_duns_lenient",
+        "{field} must be a 9-digit D-U-N-S or 13-digit D-U-N-S+4 number",
+    ),
+    ("validate_ean", "{field} must be a valid EAN"),
+    ("validate_ean13", "{field} must be a valid EAN-13"),
+    ("validate_ean8", "{field} must be a valid EAN-8"),
+    ("validate_email", "{field} must be a valid email address"),
+    (
+        "validate_email_no_disposable",
+        "{field} must be an email address that is not from a disposable provider",
+    ),
+    (
+        "validate_email_no_plus_tag",
+        "{field} must be an email address without a plus tag",
+    ),
+    (
+        "validate_env_var_assignment",
+        "{field} must be an environment variable assignment like NAME=value",
+    ),
+    (
+        "validate_env_var_name",
+        "{field} must be a valid environment variable name",
+    ),
+    (
+        "validate_env_var_name_posix_strict",
+        "{field} must be an uppercase POSIX environment variable name",
+    ),
+    (
+        "validate_gcp_project_id",
+        "{field} must be a valid Google Cloud project ID",
+    ),
+    (
+        "validate_gcp_service_account_email",
+        "{field} must be a valid Google Cloud service account email",
+    ),
+    (
+        "validate_git_branch_name",
+        "{field} must be a valid Git branch name",
+    ),
+    (
+        "validate_git_commit_sha",
+        "{field} must be a valid Git commit hash",
+    ),
+    (
+        "validate_git_commit_sha_full",
+        "{field} must be a full 40-character Git commit hash",
+    ),
+    (
+        "validate_git_commit_sha_short",
+        "{field} must be an abbreviated Git commit hash",
+    ),
+    (
+        "validate_github_full_repo",
+        "{field} must be a GitHub repository in owner/name form",
+    ),
+    (
+        "validate_github_repo_name",
+        "{field} must be a valid GitHub repository name",
+    ),
+    (
+        "validate_github_username",
+        "{field} must be a valid GitHub username",
+    ),
+    (
+        "validate_gitlab_full_path",
+        "{field} must be a valid GitLab namespace path",
+    ),
+    (
+        "validate_gitlab_project_path",
+        "{field} must be a valid GitLab project path",
+    ),
+    (
+        "validate_graphql_enum_value",
+        "{field} must be a valid GraphQL enum value",
+    ),
+    (
+        "validate_graphql_name",
+        "{field} must be a valid GraphQL name",
+    ),
+    (
+        "validate_graphql_query",
+        "{field} must be a valid GraphQL query",
+    ),
+    (
+        "validate_helm_chart_name",
+        "{field} must be a valid Helm chart name",
+    ),
+    (
+        "validate_helm_release_name",
+        "{field} must be a valid Helm release name",
+    ),
+    ("validate_hex_color", "{field} must be a valid hex color"),
+    (
+        "validate_hostname_or_ip",
+        "{field} must be a valid hostname or IP address",
+    ),
+    (
+        "validate_hostname_or_ip_no_private",
+        "{field} must be a public hostname or IP address",
+    ),
+    (
+        "validate_html_color_name",
+        "{field} must be a valid HTML color name",
+    ),
+    (
+        "validate_http_header_name",
+        "{field} must be a valid HTTP header name",
+    ),
+    (
+        "validate_http_header_value",
+        "{field} must be a valid HTTP header value",
+    ),
+    ("validate_image_extension", "{field} must be an image file"),
+    ("validate_ip", "{field} must be a valid IP address"),
+    ("validate_isbn10", "{field} must be a valid ISBN-10"),
+    ("validate_isbn13", "{field} must be a valid ISBN-13"),
+    ("validate_isin", "{field} must be a valid ISIN"),
+    (
+        "validate_jsonpath",
+        "{field} must be a valid JSONPath expression",
+    ),
+    (
+        "validate_k8s_label_key",
+        "{field} must be a valid Kubernetes label key",
+    ),
+    (
+        "validate_k8s_label_value",
+        "{field} must be a valid Kubernetes label value",
+    ),
+    (
+        "validate_k8s_namespace",
+        "{field} must be a valid Kubernetes namespace",
+    ),
+    (
+        "validate_latex_command",
+        "{field} must be a valid LaTeX command",
+    ),
+    (
+        "validate_latex_environment_name",
+        "{field} must be a valid LaTeX environment name",
+    ),
+    (
+        "validate_lei",
+        "{field} must be a valid Legal Entity Identifier",
+    ),
+    ("validate_markdown", "{field} must be valid Markdown"),
+    ("validate_name", "{field} must not contain digits"),
+    (
+        "validate_nfc_normalized",
+        "{field} must be in Unicode NFC form",
+    ),
+    ("validate_nhs_number", "{field} must be a valid NHS number"),
+    ("validate_no_html", "{field} must not contain HTML"),
+    (
+        "validate_no_repeated_characters",
+        "{field} must not repeat a character consecutively",
+    ),
+    (
+        "validate_no_unicode_lookalikes",
+        "{field} must not contain characters that imitate Latin letters",
+    ),
+    (
+        "validate_non_privileged_port",
+        "{field} must be a port number from 1024 to 65535",
+    ),
+    (
+        "validate_not_future_date",
+        "{field} must not be in the future",
+    ),
+    ("validate_not_past_date", "{field} must not be in the past"),
+    (
+        "validate_npm_package_name",
+        "{field} must be a valid npm package name",
+    ),
+    ("validate_ntp_server", "{field} must be a valid NTP server"),
+    (
+        "validate_ntp_server_no_private",
+        "{field} must be a public NTP server",
+    ),
+    (
+        "validate_open_fraction",
+        "{field} must be a number between 0 and 1, exclusive",
+    ),
+    (
+        "validate_openapi_operation_id",
+        "{field} must be a valid OpenAPI operation ID",
+    ),
+    (
+        "validate_openapi_path_template",
+        "{field} must be a valid OpenAPI path template",
+    ),
+    (
+        "validate_pangram",
+        "{field} must use every letter of the alphabet",
+    ),
+    (
+        "validate_path_no_traversal",
+        "{field} must not traverse outside its directory",
+    ),
+    (
+        "validate_payment_reference_fps",
+        "{field} must be a valid Faster Payments reference",
+    ),
+    ("validate_pci_pan", "{field} must be a valid card number"),
+    ("validate_pem_block", "{field} must be a valid PEM block"),
+    (
+        "validate_pem_certificate",
+        "{field} must be a valid PEM certificate",
+    ),
+    (
+        "validate_perfect_pangram",
+        "{field} must use every letter of the alphabet exactly once",
+    ),
+    (
+        "validate_phone_e164",
+        "{field} must be a phone number in E.164 format",
+    ),
+    (
+        "validate_port_number",
+        "{field} must be a valid port number",
+    ),
+    (
+        "validate_prometheus_label_name",
+        "{field} must be a valid Prometheus label name",
+    ),
+    (
+        "validate_prometheus_label_value",
+        "{field} must be a valid Prometheus label value",
+    ),
+    (
+        "validate_prometheus_metric_name",
+        "{field} must be a valid Prometheus metric name",
+    ),
+    (
+        "validate_proportional_fraction",
+        "{field} must be a number from 0 to 1",
+    ),
+    (
+        "validate_proto_field_name",
+        "{field} must be a valid Protocol Buffers field name",
+    ),
+    (
+        "validate_proto_message_name",
+        "{field} must be a valid Protocol Buffers message name",
+    ),
+    (
+        "validate_proto_package_name",
+        "{field} must be a valid Protocol Buffers package name",
+    ),
+    (
+        "validate_pypi_name_normalized",
+        "{field} must be a normalized PyPI package name",
+    ),
+    (
+        "validate_pypi_package_name",
+        "{field} must be a valid PyPI package name",
+    ),
+    (
+        "validate_regex_no_catastrophic_backtracking",
+        "{field} must be a regular expression without catastrophic backtracking",
+    ),
+    (
+        "validate_regex_pattern",
+        "{field} must be a valid regular expression",
+    ),
+    (
+        "validate_relative_path",
+        "{field} must not refer to a parent directory",
+    ),
+    (
+        "validate_s3_bucket_name",
+        "{field} must be a valid S3 bucket name",
+    ),
+    ("validate_sedol", "{field} must be a valid SEDOL"),
+    (
+        "validate_semver",
+        "{field} must be a valid semantic version",
+    ),
+    (
+        "validate_sql_identifier",
+        "{field} must be a valid SQL identifier",
+    ),
+    (
+        "validate_sql_identifier_quoted",
+        "{field} must be a valid SQL identifier, quoted if necessary",
+    ),
+    (
+        "validate_terraform_identifier",
+        "{field} must be a valid Terraform identifier",
+    ),
+    (
+        "validate_terraform_module_name",
+        "{field} must be a valid Terraform module name",
+    ),
+    (
+        "validate_terraform_state_key",
+        "{field} must be a valid Terraform state key",
+    ),
+    (
+        "validate_terraform_variable_name",
+        "{field} must be a valid Terraform variable name",
+    ),
+    (
+        "validate_terraform_workspace_name",
+        "{field} must be a valid Terraform workspace name",
+    ),
+    ("validate_tfn", "{field} must be a valid Australian TFN"),
+    ("validate_tld", "{field} must be a known top-level domain"),
+    (
+        "validate_tld_syntax",
+        "{field} must be a syntactically valid top-level domain",
+    ),
+    (
+        "validate_toml_bare_key",
+        "{field} must be a valid TOML bare key",
+    ),
+    (
+        "validate_toml_dotted_key",
+        "{field} must be a valid TOML dotted key",
+    ),
+    ("validate_totp_code", "{field} must be a 6- or 8-digit code"),
+    (
+        "validate_uk_sort_code",
+        "{field} must be a valid UK sort code",
+    ),
+    (
+        "validate_uk_sort_code_in_sample",
+        "{field} must be a sort code from the bundled sample",
+    ),
+    ("validate_upc_a", "{field} must be a valid UPC-A"),
+    ("validate_upc_e", "{field} must be a valid UPC-E"),
+    ("validate_url", "{field} must be a valid URL"),
+    (
+        "validate_us_state_code",
+        "{field} must be a US state or territory code",
+    ),
+    ("validate_us_state_only", "{field} must be a US state code"),
+    ("validate_uuid", "{field} must be a valid UUID"),
+    (
+        "validate_version_range",
+        "{field} must be a valid version range",
+    ),
+    (
+        "validate_well_known_port",
+        "{field} must be a port number from 0 to 1023",
+    ),
+    (
+        "validate_windows_registry_path",
+        "{field} must be a valid Windows registry path",
+    ),
+    (
+        "validate_wolfram_expression_basic",
+        "{field} must be a well-formed Wolfram Language expression",
+    ),
+    (
+        "validate_x509_subject_dn",
+        "{field} must be a valid X.509 distinguished name",
+    ),
+    ("validate_xml_name", "{field} must be a valid XML name"),
+    ("validate_xml_ncname", "{field} must be a valid XML NCName"),
+    (
+        "validate_yaml_anchor_name",
+        "{field} must be a valid YAML anchor name",
+    ),
+    (
+        "validate_yaml_bare_key",
+        "{field} must be a YAML key that needs no quoting",
+    ),
+];
+
+pub struct DefaultFormatter;
+
+impl DefaultFormatter {
+    fn template(rule: &str) -> Option<&'static str> {
+        RULE_MESSAGES
+            .binary_search_by(|(name, _)| (*name).cmp(rule))
+            .ok()
+            .map(|index| RULE_MESSAGES[index].1)
+    }
+}
+
+impl FormatterFactory for DefaultFormatter {
+    fn format(&self, error: &ValidationError, _locale: &str) -> String {
+        // Rules registered by callers get a neutral message.
+        let template = Self::template(&error.rule).unwrap_or("{field} is invalid");
+        render(template, error)
+    }
+}
+
+#[cfg(feature = "std")]
+pub struct CustomFormatter {
+    templates: HashMap<String, String>,
+}
+
+#[cfg(feature = "std")]
+impl CustomFormatter {
+    pub fn new(templates: HashMap<String, String>) -> CustomFormatter {
+        CustomFormatter { templates }
+    }
+}
+
+#[cfg(feature = "std")]
+impl FormatterFactory for CustomFormatter {
+    fn format(&self, error: &ValidationError, locale: &str) -> String {
+        let localized = format!("{}.{}", locale, error.rule);
+        match self
+            .templates
+            .get(&localized)
+            .or_else(|| self.templates.get(&error.rule))
+        {
+            Some(template) => render(template, error),
+            None => DefaultFormatter.format(error, locale),
+        }
+    }
+}
+
+fn render(template: &str, error: &ValidationError) -> String {
+    template
+        .replace("{field}", &error.field)
+        .replace("{rule}", &error.rule)
+        .replace("{message}", &error.message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ValidatorRegistry;
+
+    #[test]
+    fn test_default_formatter() {
+        let formatter = DefaultFormatter;
+        assert_eq!(
+            formatter.format(&ValidationError::new("name", "not_empty", ""), "en"),
+            "name is required"
+        );
+        assert_eq!(
+            formatter.format(&ValidationError::new("email", "validate_email", ""), "en"),
+            "email must be a valid email address"
+        );
+        assert_eq!(
+            formatter.format(
+                &ValidationError::new("bucket", "validate_s3_bucket_name", ""),
+                "en"
+            ),
+            "bucket must be a valid S3 bucket name"
+        );
+        assert_eq!(
+            formatter.format(&ValidationError::new("bio", "validate_no_html", ""), "en"),
+            "bio must not contain HTML"
+        );
+        assert_eq!(
+            formatter.format(
+                &ValidationError::new("size", "validate_shoe_size", ""),
+                "en"
+            ),
+            "size is invalid"
+        );
+    }
+
+    #[test]
+    fn test_rule_messages_sorted() {
+        assert!(RULE_MESSAGES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_default_formatter_covers_standard_registry() {
+        for name in ValidatorRegistry::standard().names() {
+            assert!(DefaultFormatter::template(name).is_some(), "{}", name);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_custom_formatter() {
+        let templates = HashMap::from([
+            (
+                "de.not_empty".to_string(),
+                "{field} ist erforderlich".to_string(),
+            ),
+            (
+                "de.validate_email".to_string(),
+                "{field} muss eine gültige E-Mail-Adresse sein".to_string(),
+            ),
+            (
+                "validate_name".to_string(),
+                "{field}: {message} ({rule})".to_string(),
+            ),
+        ]);
+        let formatter = CustomFormatter::new(templates);
+
+        assert_eq!(
+            formatter.format(&ValidationError::new("Name", "not_empty", ""), "de"),
+            "Name ist erforderlich"
+        );
+        assert_eq!(
+            formatter.format(&ValidationError::new("E-Mail", "validate_email", ""), "de"),
+            "E-Mail muss eine gültige E-Mail-Adresse sein"
+        );
+        assert_eq!(
+            formatter.format(&ValidationError::new("name", "not_empty", ""), "en"),
+            "name is required"
+        );
+        assert_eq!(
+            formatter.format(
+                &ValidationError::new("name", "validate_name", "no digits"),
+                "fr"
+            ),
+            "name: no digits (validate_name)"
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+pub fn max_graphemes(max: usize) -> impl Fn(&str) -> bool {
+    move |input| input.graphemes(true).count() <= max
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::max_length_validator;
+
+    #[test]
+    fn test_max_graphemes() {
+        let validator = max_graphemes(3);
+        assert!(validator("abc"));
+        assert!(validator("żół"));
+        assert!(!validator("abcd"));
+
+        let thumbs_up = "\u{1F44D}\u{1F3FD}";
+        assert!(max_graphemes(1)(thumbs_up));
+        assert!(!max_length_validator(1)(thumbs_up));
+
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert!(max_graphemes(1)(family));
+        assert!(max_graphemes(1)("e\u{301}"));
+    }
+}
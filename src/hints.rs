@@ -0,0 +1,227 @@
+use crate::Validator;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+pub struct ValidatorWithHints {
+    pub validator: Validator,
+    pub hints: Vec<String>,
+}
+
+impl ValidatorWithHints {
+    pub fn new(validator: Validator, hints: Vec<String>) -> Self {
+        Self { validator, hints }
+    }
+
+    pub fn read_input<T>(&self, prompt: &str) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: core::fmt::Debug,
+    {
+        self.read_input_from(prompt, &mut io::stdin().lock())
+    }
+
+    pub fn read_input_from<T, R>(&self, prompt: &str, reader: &mut R) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: core::fmt::Debug,
+        R: BufRead,
+    {
+        self.read_input_with_io(prompt, reader, &mut io::stdout())
+    }
+
+    pub fn read_input_with_io<T, R, W>(
+        &self,
+        prompt: &str,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: core::fmt::Debug,
+        R: BufRead,
+        W: Write,
+    {
+        let hints: Vec<&str> = self.hints.iter().map(String::as_str).collect();
+        read_input_with_hints_with_io(prompt, &hints, &self.validator, reader, writer)
+    }
+}
+
+/// Like [`read_input`](crate::read_input), but lists `hints` under the
+/// prompt and reports which of them the input broke.
+///
+/// Returns `None` once the input reaches end of file.
+pub fn read_input_with_hints<T>(prompt: &str, hints: &[&str], validator: &Validator) -> Option<T>
+where
+    T: FromStr,
+    T::Err: core::fmt::Debug,
+{
+    read_input_with_hints_from(prompt, hints, validator, &mut io::stdin().lock())
+}
+
+pub fn read_input_with_hints_from<T, R>(
+    prompt: &str,
+    hints: &[&str],
+    validator: &Validator,
+    reader: &mut R,
+) -> Option<T>
+where
+    T: FromStr,
+    T::Err: core::fmt::Debug,
+    R: BufRead,
+{
+    read_input_with_hints_with_io(prompt, hints, validator, reader, &mut io::stdout())
+}
+
+pub fn read_input_with_hints_with_io<T, R, W>(
+    prompt: &str,
+    hints: &[&str],
+    validator: &Validator,
+    reader: &mut R,
+    writer: &mut W,
+) -> Option<T>
+where
+    T: FromStr,
+    T::Err: core::fmt::Debug,
+    R: BufRead,
+    W: Write,
+{
+    loop {
+        writeln!(writer, "{}", prompt).expect("Failed to write prompt");
+        for hint in hints {
+            writeln!(writer, "  - {}", hint).expect("Failed to write hint");
+        }
+
+        let mut buffer = String::new();
+        let bytes_read = reader.read_line(&mut buffer).expect("Failed to read input");
+        if bytes_read == 0 {
+            return None;
+        }
+
+        let input = buffer.trim();
+
+        if let Ok(value) = input.parse::<T>() {
+            match failing_hints(input, hints, validator) {
+                Ok(()) => return Some(value),
+                Err(failed) if failed.is_empty() => {
+                    writeln!(writer, "Invalid input, please try again.")
+                        .expect("Failed to write message");
+                }
+                Err(failed) => {
+                    for hint in failed {
+                        writeln!(writer, "Invalid input: {}", hint)
+                            .expect("Failed to write message");
+                    }
+                }
+            }
+        } else {
+            writeln!(writer, "Failed to convert value, please try again.")
+                .expect("Failed to write message");
+        }
+    }
+}
+
+fn failing_hints<'a>(
+    input: &str,
+    hints: &[&'a str],
+    validator: &Validator,
+) -> Result<(), Vec<&'a str>> {
+    validator.validate_with_messages(input).map_err(|failures| {
+        if hints.len() == validator.len() {
+            failures
+                .into_iter()
+                .map(|(index, _)| hints[index])
+                .collect()
+        } else {
+            Vec::new()
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator_factory;
+    use std::io::Cursor;
+
+    const HINTS: [&str; 2] = ["Must not be empty", "Must not contain digits"];
+
+    #[test]
+    fn test_read_input_with_hints_from() {
+        let mut cursor = Cursor::new(&b"\nJohn123\nJohn\n"[..]);
+
+        let validator = validator_factory!(not_empty, validate_name);
+        let result: Option<String> =
+            read_input_with_hints_from("Enter name:", &HINTS, &validator, &mut cursor);
+        assert_eq!(result.as_deref(), Some("John"));
+    }
+
+    #[test]
+    fn test_read_input_with_hints_with_io() {
+        let mut cursor = Cursor::new(&b"John123\nJohn\n"[..]);
+        let mut output = Vec::new();
+
+        let validator = validator_factory!(not_empty, validate_name);
+        let result: Option<String> = read_input_with_hints_with_io(
+            "Enter name:",
+            &HINTS,
+            &validator,
+            &mut cursor,
+            &mut output,
+        );
+        assert_eq!(result.as_deref(), Some("John"));
+
+        let prompt = "Enter name:\n  - Must not be empty\n  - Must not contain digits\n";
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("{prompt}Invalid input: Must not contain digits\n{prompt}")
+        );
+    }
+
+    #[test]
+    fn test_read_input_with_hints_end_of_input() {
+        let mut cursor = Cursor::new(&b"John123\n"[..]);
+        let mut output = Vec::new();
+
+        let validator = validator_factory!(not_empty, validate_name);
+        let result: Option<String> = read_input_with_hints_with_io(
+            "Enter name:",
+            &HINTS,
+            &validator,
+            &mut cursor,
+            &mut output,
+        );
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_failing_hints() {
+        let validator = validator_factory!(not_empty, validate_name);
+        assert_eq!(failing_hints("John", &HINTS, &validator), Ok(()));
+        assert_eq!(
+            failing_hints("John123", &HINTS, &validator),
+            Err(vec!["Must not contain digits"])
+        );
+        assert_eq!(
+            failing_hints("John123", &HINTS[..1], &validator),
+            Err(vec![])
+        );
+    }
+
+    #[test]
+    fn test_validator_with_hints() {
+        let validator = ValidatorWithHints::new(
+            validator_factory!(not_empty, validate_name),
+            HINTS.iter().map(|hint| hint.to_string()).collect(),
+        );
+        let mut cursor = Cursor::new(&b"42\nJohn\n"[..]);
+
+        let mut output = Vec::new();
+
+        let result: Option<String> =
+            validator.read_input_with_io("Enter name:", &mut cursor, &mut output);
+        assert_eq!(result.as_deref(), Some("John"));
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Invalid input: Must not contain digits\n"));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::validators::gtin_check_digit;
+use crate::ValidationMethods;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub fn isbn10_to_isbn13(isbn10: &str) -> Option<String> {
+    if !ValidationMethods::validate_isbn10(isbn10) {
+        return None;
+    }
+
+    let mut digits = Vec::from([9, 7, 8]);
+    digits.extend(
+        strip_separators(isbn10)
+            .chars()
+            .take(9)
+            .filter_map(|c| c.to_digit(10)),
+    );
+    digits.push(gtin_check_digit(&digits));
+
+    Some(digits_to_string(&digits))
+}
+
+pub fn isbn13_to_isbn10(isbn13: &str) -> Option<String> {
+    let isbn13 = strip_separators(isbn13);
+    if !ValidationMethods::validate_isbn13(&isbn13) || !isbn13.starts_with("978") {
+        return None;
+    }
+
+    let digits: Vec<u32> = isbn13[3..12]
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .collect();
+    let sum: u32 = digits
+        .iter()
+        .zip((2..=10).rev())
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+    let mut isbn10 = digits_to_string(&digits);
+    isbn10.push(match (11 - sum % 11) % 11 {
+        10 => 'X',
+        check => char::from_digit(check, 10)?,
+    });
+
+    Some(isbn10)
+}
+
+fn strip_separators(isbn: &str) -> String {
+    isbn.chars().filter(|c| !matches!(c, '-' | ' ')).collect()
+}
+
+fn digits_to_string(digits: &[u32]) -> String {
+    digits
+        .iter()
+        .filter_map(|&digit| char::from_digit(digit, 10))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isbn10_to_isbn13() {
+        assert_eq!(
+            isbn10_to_isbn13("0-306-40615-2").as_deref(),
+            Some("9780306406157")
+        );
+        assert_eq!(
+            isbn10_to_isbn13("080442957X").as_deref(),
+            Some("9780804429573")
+        );
+        assert_eq!(isbn10_to_isbn13("0306406153"), None);
+        assert_eq!(isbn10_to_isbn13("9780306406157"), None);
+    }
+
+    #[test]
+    fn test_isbn13_to_isbn10() {
+        assert_eq!(
+            isbn13_to_isbn10("978-0-306-40615-7").as_deref(),
+            Some("0306406152")
+        );
+        assert_eq!(
+            isbn13_to_isbn10("9780804429573").as_deref(),
+            Some("080442957X")
+        );
+        assert_eq!(isbn13_to_isbn10("9791034300020"), None);
+        assert_eq!(isbn13_to_isbn10("9780306406158"), None);
+        assert_eq!(isbn13_to_isbn10("0306406152"), None);
+    }
+
+    #[test]
+    fn test_isbn_round_trip() {
+        for isbn10 in ["0306406152", "080442957X", "1861972717"] {
+            let isbn13 = isbn10_to_isbn13(isbn10).unwrap();
+            assert!(ValidationMethods::validate_isbn13(&isbn13));
+            assert_eq!(isbn13_to_isbn10(&isbn13).as_deref(), Some(isbn10));
+        }
+    }
+}
@@ -0,0 +1,417 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "regex")]
+use regex::Regex;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead, Write};
+#[cfg(feature = "std")]
+use std::str::FromStr;
+
+mod clock;
+#[cfg(feature = "std")]
+pub mod color_validators;
+#[cfg(feature = "readline")]
+mod completion;
+pub mod email_utils;
+mod error;
+#[cfg(feature = "std")]
+mod form;
+mod formatter;
+pub mod grapheme_length_validators;
+#[cfg(feature = "std")]
+mod hints;
+pub mod html5_validators;
+pub mod isbn_utils;
+mod middleware;
+mod named;
+pub mod prelude;
+mod registry;
+pub mod sanitize;
+#[cfg(feature = "std")]
+mod schema;
+#[cfg(feature = "semver")]
+pub mod semver_validators;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+pub mod string_validators;
+pub mod unicode_validators;
+mod user;
+mod validators;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod word_count_validators;
+
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+pub use clock::{Clock, Date, FixedClock};
+#[cfg(feature = "readline")]
+pub use completion::{matching_completions, read_input_with_completion};
+pub use error::{ValidationError, ValidationResult};
+#[cfg(feature = "std")]
+pub use form::{Form, FormBuilder, ValidationReport};
+#[cfg(feature = "std")]
+pub use formatter::CustomFormatter;
+pub use formatter::{DefaultFormatter, FormatterFactory};
+#[cfg(feature = "std")]
+pub use hints::{
+    read_input_with_hints, read_input_with_hints_from, read_input_with_hints_with_io,
+    ValidatorWithHints,
+};
+#[cfg(feature = "std")]
+pub use middleware::LoggingMiddleware;
+pub use middleware::{
+    LowercaseMiddleware, MiddlewareValidator, TrimMiddleware, ValidationMiddleware,
+};
+pub use named::{NamedValidator, ValidatorSet};
+pub use registry::{RegistryError, ValidatorRegistry};
+#[cfg(feature = "std")]
+pub use schema::{InputSchema, SchemaError};
+#[cfg(feature = "std")]
+pub use stream::StreamValidator;
+pub use user::{User, UserBuilder};
+pub use validators::*;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec;
+}
+
+pub struct ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_name(name: &str) -> bool {
+        !name.chars().any(|c| c.is_numeric())
+    }
+
+    #[cfg(feature = "regex")]
+    pub fn validate_email(email: &str) -> bool {
+        let email_regex = Regex::new(r"^[\w\.-]+@[\w\.-]+\.\w+$").unwrap();
+        email_regex.is_match(email)
+    }
+
+    pub fn not_empty(value: &str) -> bool {
+        !value.is_empty()
+    }
+}
+
+type Validation = Box<dyn Fn(&str) -> bool + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationLevel {
+    Error,
+    Warning,
+}
+
+pub struct Validator {
+    validations: Vec<Validation>,
+    warnings: Vec<Validation>,
+    messages: BTreeMap<usize, String>,
+}
+
+impl Validator {
+    pub fn new(validations: Vec<fn(&str) -> bool>) -> Self {
+        Self {
+            validations: validations
+                .into_iter()
+                .map(|validation| Box::new(validation) as Validation)
+                .collect(),
+            warnings: Vec::new(),
+            messages: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_validation(
+        mut self,
+        validation: impl Fn(&str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.validations.push(Box::new(validation));
+        self
+    }
+
+    pub fn with_validation_level(
+        mut self,
+        validation: impl Fn(&str) -> bool + Send + Sync + 'static,
+        level: ValidationLevel,
+    ) -> Self {
+        match level {
+            ValidationLevel::Error => self.validations.push(Box::new(validation)),
+            ValidationLevel::Warning => self.warnings.push(Box::new(validation)),
+        }
+        self
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn len(&self) -> usize {
+        self.validations.len()
+    }
+
+    pub fn validate(&self, input: &str) -> bool {
+        self.validations.iter().all(|validation| validation(input))
+    }
+
+    pub fn map_err(&mut self, index: usize, message: impl Into<String>) -> &mut Self {
+        assert!(
+            index < self.validations.len(),
+            "no validation at index {}",
+            index
+        );
+        self.messages.insert(index, message.into());
+        self
+    }
+
+    pub fn validate_with_messages(&self, input: &str) -> Result<(), Vec<(usize, String)>> {
+        let errors: Vec<(usize, String)> = self
+            .validations
+            .iter()
+            .enumerate()
+            .filter(|(_, validation)| !validation(input))
+            .map(|(index, _)| {
+                let message = self
+                    .messages
+                    .get(&index)
+                    .map_or_else(|| "Invalid input".to_string(), String::clone);
+                (index, message)
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub fn check(&self, input: &str) -> Option<ValidationLevel> {
+        if !self.validate(input) {
+            Some(ValidationLevel::Error)
+        } else if !self.warnings.iter().all(|warning| warning(input)) {
+            Some(ValidationLevel::Warning)
+        } else {
+            None
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! validator_factory {
+    ($($validation:ident),*) => {
+        $crate::Validator::new($crate::__private::vec![$($crate::ValidationMethods::$validation),*])
+    };
+}
+
+/// Prompts until `validator` accepts a line that parses as `T`.
+///
+/// Returns `None` if stdin reaches end of input first.
+#[cfg(feature = "std")]
+pub fn read_input<T>(prompt: &str, validator: &Validator) -> Option<T>
+where
+    T: FromStr,
+    T::Err: core::fmt::Debug,
+{
+    read_input_from(prompt, validator, &mut io::stdin().lock())
+}
+
+#[cfg(feature = "std")]
+pub fn read_input_from<T, R>(prompt: &str, validator: &Validator, reader: &mut R) -> Option<T>
+where
+    T: FromStr,
+    T::Err: core::fmt::Debug,
+    R: BufRead,
+{
+    read_input_with_io(prompt, validator, reader, &mut io::stdout())
+}
+
+#[cfg(feature = "std")]
+pub fn read_input_with_io<T, R, W>(
+    prompt: &str,
+    validator: &Validator,
+    reader: &mut R,
+    writer: &mut W,
+) -> Option<T>
+where
+    T: FromStr,
+    T::Err: core::fmt::Debug,
+    R: BufRead,
+    W: Write,
+{
+    loop {
+        writeln!(writer, "{}", prompt).expect("Failed to write prompt");
+
+        let mut buffer = String::new();
+        let bytes_read = reader.read_line(&mut buffer).expect("Failed to read input");
+        if bytes_read == 0 {
+            return None;
+        }
+
+        let input = buffer.trim();
+
+        if let Ok(value) = input.parse::<T>() {
+            if validator.validate(input) {
+                return Some(value);
+            } else {
+                writeln!(writer, "Invalid input, please try again.")
+                    .expect("Failed to write message");
+            }
+        } else {
+            writeln!(writer, "Failed to convert value, please try again.")
+                .expect("Failed to write message");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use std::io::BufRead;
+    #[cfg(feature = "std")]
+    use std::io::Cursor;
+
+    #[test]
+    fn test_validate_name() {
+        assert!(ValidationMethods::validate_name("John"));
+        assert!(!ValidationMethods::validate_name("John123"));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_validate_email() {
+        assert!(ValidationMethods::validate_email("test@example.com"));
+        assert!(!ValidationMethods::validate_email("invalid-email"));
+    }
+
+    #[test]
+    fn test_not_empty() {
+        assert!(ValidationMethods::not_empty("not empty"));
+        assert!(!ValidationMethods::not_empty(""));
+    }
+
+    #[test]
+    fn test_validator() {
+        let validator = validator_factory!(not_empty, validate_name);
+        assert!(validator.validate("John"));
+        assert!(!validator.validate("John123"));
+        assert!(!validator.validate(""));
+    }
+
+    #[test]
+    fn test_validator_with_validation() {
+        let validator = validator_factory!(not_empty).with_validation(max_length_validator(5));
+        assert!(validator.validate("John"));
+        assert!(!validator.validate("Johnny"));
+        assert!(!validator.validate(""));
+    }
+
+    #[test]
+    fn test_validator_check() {
+        let validator = validator_factory!(not_empty, validate_name).with_validation_level(
+            changed_from_validator("Alice".into()),
+            ValidationLevel::Warning,
+        );
+        assert_eq!(validator.check("Bob"), None);
+        assert_eq!(validator.check("alice"), Some(ValidationLevel::Warning));
+        assert_eq!(validator.check("Alice1"), Some(ValidationLevel::Error));
+        assert_eq!(validator.check(""), Some(ValidationLevel::Error));
+        assert!(validator.validate("alice"));
+    }
+
+    #[test]
+    fn test_validate_with_messages() {
+        let mut validator =
+            validator_factory!(not_empty, validate_name).with_validation(max_length_validator(5));
+        validator
+            .map_err(0, "Name is required")
+            .map_err(1, "Name must not contain digits");
+
+        assert_eq!(validator.validate_with_messages("John"), Ok(()));
+        assert_eq!(
+            validator.validate_with_messages("John123"),
+            Err(vec![
+                (1, "Name must not contain digits".to_string()),
+                (2, "Invalid input".to_string()),
+            ])
+        );
+        assert_eq!(
+            validator.validate_with_messages(""),
+            Err(vec![(0, "Name is required".to_string())])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no validation at index 2")]
+    fn test_map_err_out_of_range() {
+        validator_factory!(not_empty, validate_name).map_err(2, "Too long");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_input_from() {
+        let mut cursor = Cursor::new(&b"John123\nJohn\n"[..]);
+
+        let validator = validator_factory!(not_empty, validate_name);
+        let result: Option<String> = read_input_from("Enter name:", &validator, &mut cursor);
+        assert_eq!(result.as_deref(), Some("John"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_input_from_end_of_input() {
+        let mut cursor = Cursor::new(&b"John123\n"[..]);
+
+        let validator = validator_factory!(not_empty, validate_name);
+        let result: Option<String> = read_input_from("Enter name:", &validator, &mut cursor);
+        assert_eq!(result, None);
+
+        let mut empty = Cursor::new(&b""[..]);
+        let result: Option<u32> = read_input_from("Enter age:", &validator, &mut empty);
+        assert_eq!(result, None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_input() {
+        let input = b"John\n";
+        let mut cursor = Cursor::new(&input[..]);
+
+        let validator = validator_factory!(not_empty, validate_name);
+        let result: String = read_input_with_cursor("Enter name:", &validator, &mut cursor);
+        assert_eq!(result, "John");
+    }
+
+    #[cfg(feature = "std")]
+    fn read_input_with_cursor<T>(
+        prompt: &str,
+        validator: &Validator,
+        cursor: &mut Cursor<&[u8]>,
+    ) -> T
+    where
+        T: FromStr,
+        T::Err: core::fmt::Debug,
+    {
+        loop {
+            println!("{}", prompt);
+
+            let mut buffer = String::new();
+            cursor.read_line(&mut buffer).expect("Failed to read input");
+
+            let input = buffer.trim();
+
+            if let Ok(value) = input.parse::<T>() {
+                if validator.validate(input) {
+                    return value;
+                } else {
+                    println!("Invalid input, please try again.");
+                }
+            } else {
+                println!("Failed to convert value, please try again.");
+            }
+        }
+    }
+}
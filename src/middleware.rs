@@ -0,0 +1,149 @@
+use crate::Validator;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+pub trait ValidationMiddleware: Send + Sync {
+    fn before_validate(&self, input: &str) -> String;
+    fn after_validate(&self, input: &str, result: bool) -> bool;
+}
+
+pub struct MiddlewareValidator {
+    validator: Validator,
+    middleware: Vec<Box<dyn ValidationMiddleware>>,
+}
+
+impl MiddlewareValidator {
+    pub fn new(validator: Validator, middleware: Vec<Box<dyn ValidationMiddleware>>) -> Self {
+        Self {
+            validator,
+            middleware,
+        }
+    }
+
+    pub fn validate(&self, input: &str) -> bool {
+        let input = self
+            .middleware
+            .iter()
+            .fold(input.to_string(), |input, middleware| {
+                middleware.before_validate(&input)
+            });
+
+        let result = self.validator.validate(&input);
+
+        self.middleware.iter().fold(result, |result, middleware| {
+            middleware.after_validate(&input, result)
+        })
+    }
+}
+
+pub struct TrimMiddleware;
+
+impl ValidationMiddleware for TrimMiddleware {
+    fn before_validate(&self, input: &str) -> String {
+        input.trim().to_string()
+    }
+
+    fn after_validate(&self, _input: &str, result: bool) -> bool {
+        result
+    }
+}
+
+pub struct LowercaseMiddleware;
+
+impl ValidationMiddleware for LowercaseMiddleware {
+    fn before_validate(&self, input: &str) -> String {
+        input.to_lowercase()
+    }
+
+    fn after_validate(&self, _input: &str, result: bool) -> bool {
+        result
+    }
+}
+
+#[cfg(feature = "std")]
+pub struct LoggingMiddleware;
+
+#[cfg(feature = "std")]
+impl ValidationMiddleware for LoggingMiddleware {
+    fn before_validate(&self, input: &str) -> String {
+        input.to_string()
+    }
+
+    fn after_validate(&self, input: &str, result: bool) -> bool {
+        eprintln!("validate({:?}) -> {}", input, result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator_factory;
+    use std::sync::{Arc, Mutex};
+
+    struct RecordingMiddleware {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl ValidationMiddleware for RecordingMiddleware {
+        fn before_validate(&self, input: &str) -> String {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("before {}", self.name));
+            format!("{}{}", input, self.name)
+        }
+
+        fn after_validate(&self, input: &str, result: bool) -> bool {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("after {} {}", self.name, input));
+            result
+        }
+    }
+
+    #[test]
+    fn test_middleware_validator_chain_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let recording = |name| {
+            Box::new(RecordingMiddleware {
+                name,
+                log: Arc::clone(&log),
+            }) as Box<dyn ValidationMiddleware>
+        };
+
+        let validator = MiddlewareValidator::new(
+            validator_factory!(not_empty),
+            vec![recording("a"), recording("b")],
+        );
+        assert!(validator.validate("x"));
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["before a", "before b", "after a xab", "after b xab"]
+        );
+    }
+
+    #[test]
+    fn test_trim_and_lowercase_middleware() {
+        let validator = MiddlewareValidator::new(
+            Validator::new(vec![|input| input == "john"]),
+            vec![Box::new(TrimMiddleware), Box::new(LowercaseMiddleware)],
+        );
+        assert!(validator.validate("  JoHn  "));
+        assert!(!validator.validate("  Jane "));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_logging_middleware() {
+        let validator = MiddlewareValidator::new(
+            validator_factory!(not_empty, validate_name),
+            vec![Box::new(LoggingMiddleware)],
+        );
+        assert!(validator.validate("John"));
+        assert!(!validator.validate("John123"));
+    }
+}
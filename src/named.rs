@@ -0,0 +1,84 @@
+use crate::Validator;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::ops::Deref;
+
+pub struct NamedValidator {
+    name: String,
+    validator: Validator,
+}
+
+impl NamedValidator {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Deref for NamedValidator {
+    type Target = Validator;
+
+    fn deref(&self) -> &Validator {
+        &self.validator
+    }
+}
+
+impl Validator {
+    pub fn named(self, name: impl Into<String>) -> NamedValidator {
+        NamedValidator {
+            name: name.into(),
+            validator: self,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ValidatorSet {
+    validators: BTreeMap<String, NamedValidator>,
+}
+
+impl ValidatorSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, validator: NamedValidator) {
+        self.validators.insert(validator.name.clone(), validator);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&NamedValidator> {
+        self.validators.get(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.validators.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator_factory;
+
+    #[test]
+    fn test_named_validator() {
+        let validator = validator_factory!(not_empty, validate_name).named("person name");
+        assert_eq!(validator.name(), "person name");
+        assert!(validator.validate("John"));
+        assert!(!validator.validate("John123"));
+    }
+
+    #[test]
+    fn test_validator_set() {
+        let mut set = ValidatorSet::new();
+        set.insert(validator_factory!(not_empty, validate_name).named("name"));
+        set.insert(validator_factory!(validate_port_number).named("port"));
+
+        assert_eq!(set.names().collect::<Vec<_>>(), ["name", "port"]);
+        assert!(set.get("port").unwrap().validate("8080"));
+        assert!(!set.get("name").unwrap().validate(""));
+        assert!(set.get("email").is_none());
+
+        set.insert(validator_factory!(not_empty).named("name"));
+        assert!(set.get("name").unwrap().validate("John123"));
+    }
+}
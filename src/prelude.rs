@@ -0,0 +1,34 @@
+//! Commonly used items, importable in one line.
+//!
+//! ```
+//! use rust_user_input_validation::prelude::*;
+//!
+//! let validator = validator_factory!(not_empty, validate_name).with_validation(max_length_validator(10));
+//! assert!(validator.validate("John"));
+//! assert!(!validator.validate("John123"));
+//!
+//! let user: ValidationResult<User> = UserBuilder::new()
+//!     .name("John")
+//!     .email("john@example.com")
+//!     .age(30)
+//!     .build();
+//! assert_eq!(user.unwrap().name, "John");
+//! ```
+//!
+//! Failed builds report the field and rule through [`ValidationError`]:
+//!
+//! ```
+//! use rust_user_input_validation::prelude::*;
+//!
+//! let error: ValidationError = User::builder().name("John").build().err().unwrap();
+//! assert_eq!(error.field, "email");
+//! assert!(!ValidationMethods::not_empty(""));
+//! ```
+
+pub use crate::{
+    max_length_validator, min_length_validator, validator_factory, User, UserBuilder,
+    ValidationError, ValidationLevel, ValidationMethods, ValidationResult, Validator,
+    ValidatorRegistry,
+};
+#[cfg(feature = "std")]
+pub use crate::{read_input, read_input_from, read_input_with_io};
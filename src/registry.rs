@@ -0,0 +1,325 @@
+use crate::{ValidationMethods, Validator};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+// Prefix that wraps a registered validator in
+// `ValidationMethods::validate_optional_format`, e.g. `optional:validate_email`.
+const OPTIONAL_PREFIX: &str = "optional:";
+
+pub type RegisteredValidation = Box<dyn Fn(&str) -> bool + Send + Sync>;
+
+macro_rules! register_methods {
+    ($registry:ident, $($name:ident),* $(,)?) => {
+        $($registry.register(stringify!($name), ValidationMethods::$name);)*
+    };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    UnknownValidator(String),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::UnknownValidator(name) => write!(f, "unknown validator `{}`", name),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegistryError {}
+
+#[derive(Default)]
+pub struct ValidatorRegistry {
+    validations: BTreeMap<String, fn(&str) -> bool>,
+}
+
+impl ValidatorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn standard() -> Self {
+        let mut registry = Self::new();
+        register_methods!(
+            registry,
+            not_empty,
+            validate_aba_routing,
+            validate_android_package_name,
+            validate_android_package_name_play_store,
+            validate_any_css_color,
+            validate_apple_bundle_id,
+            validate_apple_bundle_id_strict,
+            validate_aws_arn,
+            validate_azure_resource_group_name,
+            validate_azure_storage_account_name,
+            validate_azure_subscription_id,
+            validate_base64,
+            validate_bic,
+            validate_bic_with_country_check,
+            validate_bsn,
+            validate_canadian_sin,
+            validate_conda_build_string,
+            validate_conda_package_name,
+            validate_crate_name,
+            validate_crate_name_strict,
+            validate_cron_expression,
+            validate_csp_directive,
+            validate_csp_policy,
+            validate_data_uri,
+            validate_docker_image_name,
+            validate_docker_tag,
+            validate_docker_tag_no_latest,
+            validate_domain,
+            validate_duns,
+            validate_duns_lenient,
+            validate_ean,
+            validate_ean13,
+            validate_ean8,
+            validate_email_no_disposable,
+            validate_email_no_plus_tag,
+            validate_env_var_assignment,
+            validate_env_var_name,
+            validate_env_var_name_posix_strict,
+            validate_gcp_project_id,
+            validate_gcp_service_account_email,
+            validate_git_branch_name,
+            validate_git_commit_sha,
+            validate_git_commit_sha_full,
+            validate_git_commit_sha_short,
+            validate_github_full_repo,
+            validate_github_repo_name,
+            validate_github_username,
+            validate_gitlab_full_path,
+            validate_gitlab_project_path,
+            validate_graphql_enum_value,
+            validate_graphql_name,
+            validate_graphql_query,
+            validate_helm_chart_name,
+            validate_helm_release_name,
+            validate_hex_color,
+            validate_hostname_or_ip,
+            validate_hostname_or_ip_no_private,
+            validate_html_color_name,
+            validate_http_header_name,
+            validate_http_header_value,
+            validate_image_extension,
+            validate_ip,
+            validate_isbn10,
+            validate_isbn13,
+            validate_isin,
+            validate_jsonpath,
+            validate_k8s_label_key,
+            validate_k8s_label_value,
+            validate_k8s_namespace,
+            validate_latex_command,
+            validate_latex_environment_name,
+            validate_lei,
+            validate_markdown,
+            validate_name,
+            validate_nfc_normalized,
+            validate_nhs_number,
+            validate_no_html,
+            validate_no_repeated_characters,
+            validate_no_unicode_lookalikes,
+            validate_non_privileged_port,
+            validate_npm_package_name,
+            validate_ntp_server,
+            validate_ntp_server_no_private,
+            validate_open_fraction,
+            validate_openapi_operation_id,
+            validate_openapi_path_template,
+            validate_pangram,
+            validate_path_no_traversal,
+            validate_payment_reference_fps,
+            validate_pci_pan,
+            validate_pem_block,
+            validate_pem_certificate,
+            validate_perfect_pangram,
+            validate_phone_e164,
+            validate_port_number,
+            validate_prometheus_label_name,
+            validate_prometheus_label_value,
+            validate_prometheus_metric_name,
+            validate_proportional_fraction,
+            validate_proto_field_name,
+            validate_proto_message_name,
+            validate_proto_package_name,
+            validate_pypi_name_normalized,
+            validate_pypi_package_name,
+            validate_regex_no_catastrophic_backtracking,
+            validate_s3_bucket_name,
+            validate_sedol,
+            validate_semver,
+            validate_sql_identifier,
+            validate_sql_identifier_quoted,
+            validate_terraform_identifier,
+            validate_terraform_module_name,
+            validate_terraform_state_key,
+            validate_terraform_variable_name,
+            validate_terraform_workspace_name,
+            validate_tfn,
+            validate_tld,
+            validate_tld_syntax,
+            validate_toml_bare_key,
+            validate_toml_dotted_key,
+            validate_totp_code,
+            validate_uk_sort_code,
+            validate_upc_a,
+            validate_upc_e,
+            validate_url,
+            validate_us_state_code,
+            validate_us_state_only,
+            validate_uuid,
+            validate_version_range,
+            validate_well_known_port,
+            validate_windows_registry_path,
+            validate_wolfram_expression_basic,
+            validate_x509_subject_dn,
+            validate_xml_name,
+            validate_xml_ncname,
+            validate_yaml_anchor_name,
+            validate_yaml_bare_key,
+        );
+        #[cfg(feature = "std")]
+        register_methods!(
+            registry,
+            validate_credit_card_expiry,
+            validate_credit_card_expiry_future,
+            validate_cve_id,
+            validate_not_future_date,
+            validate_not_past_date,
+            validate_relative_path,
+        );
+        #[cfg(feature = "regex")]
+        register_methods!(registry, validate_email, validate_regex_pattern);
+        #[cfg(feature = "sample-sort-codes")]
+        register_methods!(registry, validate_uk_sort_code_in_sample);
+        registry
+    }
+
+    pub fn register(&mut self, name: impl Into<String>, validation: fn(&str) -> bool) {
+        self.validations.insert(name.into(), validation);
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.validations.keys().map(String::as_str)
+    }
+
+    pub fn get(&self, name: &str) -> Option<fn(&str) -> bool> {
+        self.validations.get(name).copied()
+    }
+
+    /// Looks up `name` like [`get`](Self::get), also accepting
+    /// `optional:<name>` to let empty input through that validator.
+    pub fn resolve(&self, name: &str) -> Option<RegisteredValidation> {
+        match name.strip_prefix(OPTIONAL_PREFIX) {
+            Some(inner) => self.get(inner).map(|validation| {
+                Box::new(ValidationMethods::validate_optional_format(validation))
+                    as RegisteredValidation
+            }),
+            None => self
+                .get(name)
+                .map(|validation| Box::new(validation) as RegisteredValidation),
+        }
+    }
+}
+
+impl FromStr for Validator {
+    type Err = RegistryError;
+
+    fn from_str(names: &str) -> Result<Self, Self::Err> {
+        let registry = ValidatorRegistry::standard();
+        names
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .try_fold(Validator::new(Vec::new()), |validator, name| {
+                registry
+                    .resolve(name)
+                    .map(|validation| validator.with_validation(validation))
+                    .ok_or_else(|| RegistryError::UnknownValidator(name.to_string()))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validator_registry() {
+        let mut registry = ValidatorRegistry::new();
+        assert!(registry.get("not_empty").is_none());
+
+        registry.register("no_spaces", |input| !input.contains(' '));
+        let validation = registry.get("no_spaces").unwrap();
+        assert!(validation("John"));
+        assert!(!validation("John Smith"));
+    }
+
+    #[test]
+    fn test_validator_registry_standard() {
+        let registry = ValidatorRegistry::standard();
+        let validation = registry.get("validate_port_number").unwrap();
+        assert!(validation("8080"));
+        assert!(!validation("65536"));
+        assert!(registry.get("validate_unknown").is_none());
+    }
+
+    #[test]
+    fn test_validator_from_str() {
+        let validator: Validator = "not_empty, validate_name".parse().unwrap();
+        assert!(validator.validate("John"));
+        assert!(!validator.validate("John123"));
+        assert!(!validator.validate(""));
+
+        assert_eq!(
+            "not_empty,validate_unknown".parse::<Validator>().err(),
+            Some(RegistryError::UnknownValidator("validate_unknown".into()))
+        );
+    }
+
+    #[test]
+    fn test_validator_registry_resolve_optional() {
+        let registry = ValidatorRegistry::standard();
+        let validation = registry.resolve("optional:validate_name").unwrap();
+        assert!(validation(""));
+        assert!(validation("John"));
+        assert!(!validation("John123"));
+
+        let required = registry.resolve("validate_name").unwrap();
+        assert!(!required("John123"));
+        assert!(registry.resolve("optional:validate_unknown").is_none());
+        assert!(registry.resolve("optional:").is_none());
+    }
+
+    #[test]
+    fn test_validator_from_str_optional() {
+        let validator: Validator = "optional:validate_name".parse().unwrap();
+        assert!(validator.validate(""));
+        assert!(validator.validate("John"));
+        assert!(!validator.validate("John123"));
+
+        assert_eq!(
+            "optional:validate_unknown".parse::<Validator>().err(),
+            Some(RegistryError::UnknownValidator(
+                "optional:validate_unknown".into()
+            ))
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_validator_from_str_email() {
+        let validator: Validator = "not_empty,validate_email".parse().unwrap();
+        assert!(validator.validate("test@example.com"));
+        assert!(!validator.validate("invalid-email"));
+        assert!(!validator.validate(""));
+    }
+}
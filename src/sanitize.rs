@@ -0,0 +1,95 @@
+use crate::validators::{is_fps_reference_char, FPS_REFERENCE_MAX_LENGTH};
+use alloc::string::String;
+use unicode_normalization::UnicodeNormalization;
+
+pub fn normalize_nfc(input: &str) -> String {
+    input.nfc().collect()
+}
+
+pub fn sanitize_payment_reference(reference: &str) -> String {
+    let reference: String = reference
+        .chars()
+        .filter_map(|c| match c {
+            '\r' | '\n' | '\t' => Some(' '),
+            c if is_fps_reference_char(c) => Some(c),
+            _ => None,
+        })
+        .collect();
+
+    reference
+        .trim()
+        .chars()
+        .take(FPS_REFERENCE_MAX_LENGTH)
+        .collect::<String>()
+        .trim_end()
+        .into()
+}
+
+pub fn sanitize_sin(sin: &str) -> String {
+    sin.chars().filter(|c| !matches!(c, '-' | ' ')).collect()
+}
+
+pub fn mask_pan(pan: &str) -> String {
+    let digits: String = pan.chars().filter(|c| !matches!(c, ' ' | '-')).collect();
+    let visible_from = digits.chars().count().saturating_sub(4);
+
+    digits
+        .chars()
+        .enumerate()
+        .map(|(index, c)| if index < visible_from { '*' } else { c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_normalize_nfc() {
+        assert_eq!(normalize_nfc("cafe\u{301}"), "caf\u{e9}");
+        assert_eq!(normalize_nfc("caf\u{e9}"), "caf\u{e9}");
+        assert!(ValidationMethods::validate_nfc_normalized(&normalize_nfc(
+            "A\u{30a}ngstr\u{f6}m"
+        )));
+    }
+
+    #[test]
+    fn test_sanitize_payment_reference() {
+        assert_eq!(sanitize_payment_reference("  RENT\tMAY\r\n"), "RENT MAY");
+        assert_eq!(sanitize_payment_reference("INV|001"), "INV001");
+        assert_eq!(sanitize_payment_reference("CAFÉ 12"), "CAF 12");
+        assert_eq!(
+            sanitize_payment_reference("ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+            "ABCDEFGHIJKLMNOPQR"
+        );
+        assert_eq!(
+            sanitize_payment_reference("ABCDEFGHIJKLMNOPQ RST"),
+            "ABCDEFGHIJKLMNOPQ"
+        );
+        for reference in ["  RENT\tMAY\r\n", "INV|001", "ABCDEFGHIJKLMNOPQ RST"] {
+            assert!(ValidationMethods::validate_payment_reference_fps(
+                &sanitize_payment_reference(reference)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_sanitize_sin() {
+        assert_eq!(sanitize_sin("130-692-544"), "130692544");
+        assert_eq!(sanitize_sin(" 130 692 544 "), "130692544");
+        assert_eq!(sanitize_sin("130692544"), "130692544");
+        assert!(ValidationMethods::validate_canadian_sin(&sanitize_sin(
+            "130 692-544"
+        )));
+    }
+
+    #[test]
+    fn test_mask_pan() {
+        assert_eq!(mask_pan("4111111111111234"), "************1234");
+        assert_eq!(mask_pan("4111 1111 1111 1234"), "************1234");
+        assert_eq!(mask_pan("3782-822463-10005"), "***********0005");
+        assert_eq!(mask_pan("1234"), "1234");
+        assert_eq!(mask_pan(""), "");
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{max_length_validator, min_length_validator, Validator, ValidatorRegistry};
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Deserialize)]
+pub struct InputSchema {
+    pub field: String,
+    #[serde(default)]
+    pub rules: Vec<String>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SchemaError {
+    UnknownRule(String),
+    InvalidLengthBounds { min: usize, max: usize },
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaError::UnknownRule(rule) => write!(f, "unknown validation rule `{}`", rule),
+            SchemaError::InvalidLengthBounds { min, max } => {
+                write!(f, "min_length {} is greater than max_length {}", min, max)
+            }
+        }
+    }
+}
+
+impl Error for SchemaError {}
+
+impl InputSchema {
+    pub fn to_validator(&self, registry: &ValidatorRegistry) -> Result<Validator, SchemaError> {
+        if let (Some(min), Some(max)) = (self.min_length, self.max_length) {
+            if min > max {
+                return Err(SchemaError::InvalidLengthBounds { min, max });
+            }
+        }
+
+        let mut validator =
+            self.rules
+                .iter()
+                .try_fold(Validator::new(Vec::new()), |validator, rule| {
+                    registry
+                        .resolve(rule)
+                        .map(|validation| validator.with_validation(validation))
+                        .ok_or_else(|| SchemaError::UnknownRule(rule.clone()))
+                })?;
+        if let Some(min) = self.min_length {
+            validator = validator.with_validation(min_length_validator(min));
+        }
+        if let Some(max) = self.max_length {
+            validator = validator.with_validation(max_length_validator(max));
+        }
+        Ok(validator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> InputSchema {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_input_schema_to_validator() {
+        let schema = parse(
+            r#"{ "field": "email", "rules": ["not_empty", "validate_email"], "min_length": 5, "max_length": 254 }"#,
+        );
+        assert_eq!(schema.field, "email");
+
+        let validator = schema.to_validator(&ValidatorRegistry::standard()).unwrap();
+        assert!(validator.validate("test@example.com"));
+        assert!(validator.validate("a@b.c"));
+        assert!(!validator.validate("invalid-email"));
+        assert!(!validator.validate(&format!("{}@example.com", "a".repeat(250))));
+    }
+
+    #[test]
+    fn test_input_schema_defaults() {
+        let schema = parse(r#"{ "field": "nickname" }"#);
+        let validator = schema.to_validator(&ValidatorRegistry::standard()).unwrap();
+        assert!(validator.validate(""));
+    }
+
+    #[test]
+    fn test_input_schema_optional_rule() {
+        let schema = parse(r#"{ "field": "nickname", "rules": ["optional:validate_name"] }"#);
+        let validator = schema.to_validator(&ValidatorRegistry::standard()).unwrap();
+        assert!(validator.validate(""));
+        assert!(validator.validate("John"));
+        assert!(!validator.validate("John123"));
+    }
+
+    #[test]
+    fn test_input_schema_errors() {
+        let registry = ValidatorRegistry::standard();
+
+        let unknown = parse(r#"{ "field": "name", "rules": ["not_empty", "validate_shoe_size"] }"#);
+        assert_eq!(
+            unknown.to_validator(&registry).err(),
+            Some(SchemaError::UnknownRule("validate_shoe_size".into()))
+        );
+
+        let bounds = parse(r#"{ "field": "name", "min_length": 10, "max_length": 5 }"#);
+        assert_eq!(
+            bounds.to_validator(&registry).err(),
+            Some(SchemaError::InvalidLengthBounds { min: 10, max: 5 })
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use crate::ValidationMethods;
+use semver::{Version, VersionReq};
+
+pub fn validate_compatible(version: &str, requirement: &str) -> bool {
+    if !ValidationMethods::validate_semver(version) {
+        return false;
+    }
+
+    match (Version::parse(version), VersionReq::parse(requirement)) {
+        (Ok(version), Ok(requirement)) => requirement.matches(&version),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_compatible() {
+        assert!(validate_compatible("1.5.0", "^1.2.0"));
+        assert!(!validate_compatible("1.5.0", "^2.0.0"));
+        assert!(validate_compatible("1.5.0", "1.2.0"));
+        assert!(!validate_compatible("0.3.0", "0.2.1"));
+        assert!(validate_compatible("1.2.9", "~1.2.3"));
+        assert!(!validate_compatible("1.3.0", "~1.2.3"));
+        assert!(validate_compatible("1.2.3", "=1.2.3"));
+        assert!(!validate_compatible("1.2.4", "=1.2.3"));
+        assert!(validate_compatible("1.4.0", ">=1.2.0, <2.0.0"));
+        assert!(!validate_compatible("2.0.0", ">=1.2.0, <2.0.0"));
+        assert!(!validate_compatible("2.0.0-beta.1", "^1.0.0"));
+        assert!(!validate_compatible("1.5", "^1.2.0"));
+        assert!(!validate_compatible("1.5.0", "not a requirement"));
+    }
+}
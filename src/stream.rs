@@ -0,0 +1,141 @@
+use crate::Validator;
+use std::io::{self, ErrorKind, Read};
+
+pub struct StreamValidator {
+    validator: Validator,
+}
+
+impl StreamValidator {
+    pub fn new(validator: Validator) -> Self {
+        Self { validator }
+    }
+
+    pub fn validate_reader<R: Read>(&self, mut reader: R) -> Result<bool, io::Error> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Ok(self.validator.validate(&input))
+    }
+
+    pub fn validate_chunks<'a, R: Read + 'a>(
+        &'a self,
+        mut reader: R,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = bool> + 'a {
+        assert!(chunk_size > 0, "chunk size must be greater than zero");
+
+        let mut pending = Vec::new();
+        let mut finished = false;
+
+        std::iter::from_fn(move || {
+            if finished {
+                return None;
+            }
+
+            // Buffer enough bytes to complete a multi-byte character at the chunk boundary.
+            if fill(&mut reader, &mut pending, chunk_size + 3).is_err() {
+                finished = true;
+                return Some(false);
+            }
+            if pending.is_empty() {
+                finished = true;
+                return None;
+            }
+
+            let mut end = chunk_size.min(pending.len());
+            while end < pending.len() && is_continuation_byte(pending[end]) {
+                end += 1;
+            }
+
+            let chunk: Vec<u8> = pending.drain(..end).collect();
+            match String::from_utf8(chunk) {
+                Ok(chunk) => Some(self.validator.validate(&chunk)),
+                Err(_) => {
+                    finished = true;
+                    Some(false)
+                }
+            }
+        })
+    }
+}
+
+fn fill<R: Read>(reader: &mut R, pending: &mut Vec<u8>, target: usize) -> io::Result<()> {
+    let mut buffer = [0u8; 4096];
+    while pending.len() < target {
+        let wanted = (target - pending.len()).min(buffer.len());
+        match reader.read(&mut buffer[..wanted]) {
+            Ok(0) => break,
+            Ok(read) => pending.extend_from_slice(&buffer[..read]),
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(())
+}
+
+fn is_continuation_byte(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{max_length_validator, validator_factory};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_validate_reader() {
+        let validator = StreamValidator::new(
+            validator_factory!(not_empty).with_validation(max_length_validator(10_000)),
+        );
+
+        let text = "lorem ipsum ".repeat(500);
+        assert!(validator
+            .validate_reader(Cursor::new(text.as_bytes()))
+            .unwrap());
+
+        let text = "lorem ipsum ".repeat(1_000);
+        assert!(!validator
+            .validate_reader(Cursor::new(text.as_bytes()))
+            .unwrap());
+
+        assert!(!validator.validate_reader(Cursor::new(b"")).unwrap());
+        assert!(validator
+            .validate_reader(Cursor::new(&[0xff, 0xfe][..]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_chunks() {
+        let validator = StreamValidator::new(validator_factory!(validate_name));
+
+        let text = "abcdefghij".repeat(100);
+        let results: Vec<bool> = validator
+            .validate_chunks(Cursor::new(text.as_bytes()), 64)
+            .collect();
+        assert_eq!(results.len(), 16);
+        assert!(results.iter().all(|&valid| valid));
+
+        let text = format!("{}1{}", "a".repeat(100), "b".repeat(100));
+        let results: Vec<bool> = validator
+            .validate_chunks(Cursor::new(text.as_bytes()), 50)
+            .collect();
+        assert_eq!(results, [true, true, false, true, true]);
+    }
+
+    #[test]
+    fn test_validate_chunks_multibyte_boundary() {
+        let validator = StreamValidator::new(validator_factory!(not_empty));
+
+        let text = "żółw".repeat(10);
+        let chunks: Vec<bool> = validator
+            .validate_chunks(Cursor::new(text.as_bytes()), 3)
+            .collect();
+        assert!(!chunks.is_empty());
+        assert!(chunks.iter().all(|&valid| valid));
+
+        let results: Vec<bool> = validator
+            .validate_chunks(Cursor::new(&[b'a', b'b', 0xff, b'c'][..]), 2)
+            .collect();
+        assert_eq!(results, [true, false]);
+    }
+}
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+pub fn validate_min_entropy(min_bits: f64) -> impl Fn(&str) -> bool {
+    move |input| compute_entropy(input) >= min_bits
+}
+
+pub fn compute_entropy(input: &str) -> f64 {
+    let mut frequencies: HashMap<char, usize> = HashMap::new();
+    for c in input.chars() {
+        *frequencies.entry(c).or_default() += 1;
+    }
+
+    let total = input.chars().count() as f64;
+    frequencies
+        .values()
+        .map(|&count| {
+            let probability = count as f64 / total;
+            -probability * probability.log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_entropy() {
+        assert_eq!(compute_entropy(""), 0.0);
+        assert_eq!(compute_entropy("aaaaaaa"), 0.0);
+        assert_eq!(compute_entropy("ab"), 1.0);
+        assert_eq!(compute_entropy("aB3!xZ9&"), 3.0);
+        assert!((compute_entropy("aab") - 0.918_295_834).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_validate_min_entropy() {
+        let validator = validate_min_entropy(2.0);
+        assert!(!validator("aaaaaaa"));
+        assert!(!validator("abab"));
+        assert!(validator("aB3!xZ9&"));
+        assert!(validator("abcd"));
+    }
+}
@@ -0,0 +1,77 @@
+use core::ops::RangeInclusive;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnicodeBlock {
+    BasicLatin,
+    Latin1Supplement,
+    LatinExtendedA,
+    GreekAndCoptic,
+    Cyrillic,
+    Hebrew,
+    Arabic,
+    Hiragana,
+    Katakana,
+    CjkUnifiedIdeographs,
+    HangulSyllables,
+}
+
+impl UnicodeBlock {
+    pub fn range(self) -> RangeInclusive<char> {
+        match self {
+            Self::BasicLatin => '\u{0000}'..='\u{007F}',
+            Self::Latin1Supplement => '\u{0080}'..='\u{00FF}',
+            Self::LatinExtendedA => '\u{0100}'..='\u{017F}',
+            Self::GreekAndCoptic => '\u{0370}'..='\u{03FF}',
+            Self::Cyrillic => '\u{0400}'..='\u{04FF}',
+            Self::Hebrew => '\u{0590}'..='\u{05FF}',
+            Self::Arabic => '\u{0600}'..='\u{06FF}',
+            Self::Hiragana => '\u{3040}'..='\u{309F}',
+            Self::Katakana => '\u{30A0}'..='\u{30FF}',
+            Self::CjkUnifiedIdeographs => '\u{4E00}'..='\u{9FFF}',
+            Self::HangulSyllables => '\u{AC00}'..='\u{D7AF}',
+        }
+    }
+
+    pub fn contains(self, c: char) -> bool {
+        self.range().contains(&c)
+    }
+}
+
+pub fn validate_in_block(block: UnicodeBlock) -> impl Fn(&str) -> bool {
+    move |input| input.chars().all(|c| block.contains(c))
+}
+
+pub fn validate_only_ascii_printable(input: &str) -> bool {
+    input.chars().all(|c| (' '..='~').contains(&c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_in_block() {
+        let basic_latin = validate_in_block(UnicodeBlock::BasicLatin);
+        let cjk = validate_in_block(UnicodeBlock::CjkUnifiedIdeographs);
+        assert!(basic_latin("Hello, world!"));
+        assert!(!basic_latin("漢字文化"));
+        assert!(cjk("漢字文化"));
+        assert!(!cjk("漢字 abc"));
+
+        assert!(validate_in_block(UnicodeBlock::Cyrillic)("Жизнь"));
+        assert!(validate_in_block(UnicodeBlock::GreekAndCoptic)("αβγ"));
+        assert!(validate_in_block(UnicodeBlock::Arabic)("مرحبا"));
+        assert!(validate_in_block(UnicodeBlock::Latin1Supplement)("éü"));
+        assert!(!validate_in_block(UnicodeBlock::Latin1Supplement)("żółw"));
+    }
+
+    #[test]
+    fn test_validate_only_ascii_printable() {
+        assert!(validate_only_ascii_printable("Hello, world! ~"));
+        assert!(validate_only_ascii_printable(""));
+        assert!(!validate_only_ascii_printable("tab\there"));
+        assert!(!validate_only_ascii_printable("line\n"));
+        assert!(!validate_only_ascii_printable("\u{7F}"));
+        assert!(!validate_only_ascii_printable("café"));
+    }
+}
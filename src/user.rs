@@ -0,0 +1,157 @@
+use crate::{ValidationError, ValidationMethods, ValidationResult};
+use alloc::string::String;
+
+pub struct User {
+    pub name: String,
+    pub email: String,
+    pub age: u32,
+}
+
+impl User {
+    pub fn new(name: impl Into<String>, email: impl Into<String>, age: u32) -> Self {
+        Self {
+            name: name.into(),
+            email: email.into(),
+            age,
+        }
+    }
+
+    pub fn builder() -> UserBuilder {
+        UserBuilder::new()
+    }
+}
+
+#[derive(Default)]
+pub struct UserBuilder {
+    name: Option<String>,
+    email: Option<String>,
+    age: Option<u32>,
+}
+
+impl UserBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    pub fn age(mut self, age: u32) -> Self {
+        self.age = Some(age);
+        self
+    }
+
+    pub fn build(self) -> ValidationResult<User> {
+        let name = self.name.unwrap_or_default();
+        if !ValidationMethods::not_empty(&name) {
+            return Err(ValidationError::new(
+                "name",
+                "not_empty",
+                "Name is required",
+            ));
+        }
+        if !ValidationMethods::validate_name(&name) {
+            return Err(ValidationError::new(
+                "name",
+                "validate_name",
+                "Name must not contain digits",
+            ));
+        }
+
+        let email = self.email.unwrap_or_default();
+        if !ValidationMethods::not_empty(&email) {
+            return Err(ValidationError::new(
+                "email",
+                "not_empty",
+                "Email is required",
+            ));
+        }
+        if !is_email(&email) {
+            return Err(ValidationError::new(
+                "email",
+                "validate_email",
+                "Invalid email address",
+            ));
+        }
+
+        match self.age {
+            Some(age) => Ok(User::new(name, email, age)),
+            None => Err(ValidationError::new("age", "required", "Age is required")),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+fn is_email(email: &str) -> bool {
+    ValidationMethods::validate_email(email)
+}
+
+#[cfg(not(feature = "regex"))]
+fn is_email(email: &str) -> bool {
+    crate::html5_validators::email(email)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_builder() {
+        let user = User::builder()
+            .name("John")
+            .email("john@example.com")
+            .age(30)
+            .build()
+            .unwrap();
+        assert_eq!(user.name, "John");
+        assert_eq!(user.email, "john@example.com");
+        assert_eq!(user.age, 30);
+    }
+
+    #[test]
+    fn test_user_builder_errors() {
+        let error = UserBuilder::new()
+            .email("john@example.com")
+            .age(30)
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            (error.field.as_str(), error.rule.as_str()),
+            ("name", "not_empty")
+        );
+
+        let error = UserBuilder::new().name("John123").build().err().unwrap();
+        assert_eq!(error.rule, "validate_name");
+
+        let error = UserBuilder::new()
+            .name("John")
+            .email("invalid-email")
+            .age(30)
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            (error.field.as_str(), error.rule.as_str()),
+            ("email", "validate_email")
+        );
+
+        let error = UserBuilder::new()
+            .name("John")
+            .email("john@example.com")
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            (error.field.as_str(), error.rule.as_str()),
+            ("age", "required")
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_android_package_name(name: &str) -> bool {
+        name.len() <= 255
+            && name.split('.').count() >= 2
+            && name.split('.').all(|segment| {
+                let mut chars = segment.chars();
+                chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+                    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+            })
+    }
+
+    pub fn validate_android_package_name_play_store(name: &str) -> bool {
+        Self::validate_android_package_name(name)
+            && name.split('.').count() >= 3
+            && name.split('.').all(|segment| segment.len() > 1)
+    }
+
+    pub fn validate_apple_bundle_id(id: &str) -> bool {
+        id.len() <= 255
+            && id.split('.').count() >= 2
+            && id.split('.').all(|component| {
+                let mut chars = component.chars();
+                chars.next().is_some_and(|c| c.is_ascii_lowercase())
+                    && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            })
+    }
+
+    pub fn validate_apple_bundle_id_strict(id: &str) -> bool {
+        Self::validate_apple_bundle_id(id) && id.split('.').next().is_some_and(Self::validate_tld)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+    use alloc::format;
+
+    #[test]
+    fn test_validate_android_package_name() {
+        assert!(ValidationMethods::validate_android_package_name(
+            "com.example.myapp"
+        ));
+        assert!(ValidationMethods::validate_android_package_name("a.b"));
+        assert!(ValidationMethods::validate_android_package_name(
+            "com.Example.My_App2"
+        ));
+        assert!(!ValidationMethods::validate_android_package_name(
+            "com.1invalid.app"
+        ));
+        assert!(!ValidationMethods::validate_android_package_name("myapp"));
+        assert!(!ValidationMethods::validate_android_package_name(
+            "com..example"
+        ));
+        assert!(!ValidationMethods::validate_android_package_name(
+            ".com.example"
+        ));
+        assert!(!ValidationMethods::validate_android_package_name(
+            "com.example."
+        ));
+        assert!(!ValidationMethods::validate_android_package_name(
+            "com._example"
+        ));
+        assert!(!ValidationMethods::validate_android_package_name(
+            "com.my-app"
+        ));
+        assert!(!ValidationMethods::validate_android_package_name(""));
+
+        let too_long = format!("com.{}", "a".repeat(252));
+        assert!(!ValidationMethods::validate_android_package_name(&too_long));
+        let longest = format!("com.{}", "a".repeat(251));
+        assert!(ValidationMethods::validate_android_package_name(&longest));
+    }
+
+    #[test]
+    fn test_validate_android_package_name_play_store() {
+        assert!(ValidationMethods::validate_android_package_name_play_store(
+            "com.example.myapp"
+        ));
+        assert!(!ValidationMethods::validate_android_package_name_play_store("a.b"));
+        assert!(!ValidationMethods::validate_android_package_name_play_store("com.example"));
+        assert!(!ValidationMethods::validate_android_package_name_play_store("com.x.myapp"));
+        assert!(!ValidationMethods::validate_android_package_name_play_store("com.1invalid.app"));
+    }
+
+    #[test]
+    fn test_validate_apple_bundle_id() {
+        assert!(ValidationMethods::validate_apple_bundle_id(
+            "com.example.my-app"
+        ));
+        assert!(ValidationMethods::validate_apple_bundle_id(
+            "io.vertyll.app2"
+        ));
+        assert!(ValidationMethods::validate_apple_bundle_id(
+            "internal.tools"
+        ));
+        assert!(!ValidationMethods::validate_apple_bundle_id(
+            "com.example.MyApp"
+        ));
+        assert!(!ValidationMethods::validate_apple_bundle_id("..bad"));
+        assert!(!ValidationMethods::validate_apple_bundle_id("com..example"));
+        assert!(!ValidationMethods::validate_apple_bundle_id("com.example."));
+        assert!(!ValidationMethods::validate_apple_bundle_id("com.1example"));
+        assert!(!ValidationMethods::validate_apple_bundle_id("com.-example"));
+        assert!(!ValidationMethods::validate_apple_bundle_id("com.my_app"));
+        assert!(!ValidationMethods::validate_apple_bundle_id("myapp"));
+        assert!(!ValidationMethods::validate_apple_bundle_id(""));
+
+        let too_long = format!("com.{}", "a".repeat(252));
+        assert!(!ValidationMethods::validate_apple_bundle_id(&too_long));
+    }
+
+    #[test]
+    fn test_validate_apple_bundle_id_strict() {
+        assert!(ValidationMethods::validate_apple_bundle_id_strict(
+            "com.example.my-app"
+        ));
+        assert!(ValidationMethods::validate_apple_bundle_id_strict(
+            "io.vertyll.app"
+        ));
+        assert!(!ValidationMethods::validate_apple_bundle_id_strict(
+            "internal.tools"
+        ));
+        assert!(!ValidationMethods::validate_apple_bundle_id_strict(
+            "com.example.MyApp"
+        ));
+        assert!(!ValidationMethods::validate_apple_bundle_id_strict("..bad"));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::ValidationMethods;
+use core::net::Ipv4Addr;
+
+impl ValidationMethods {
+    pub fn validate_s3_bucket_name(name: &str) -> bool {
+        let is_edge = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+
+        (3..=63).contains(&name.len())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '.'))
+            && name.starts_with(is_edge)
+            && name.ends_with(is_edge)
+            && !name.contains("--")
+            && !name.contains("..")
+            && !name.contains(".-")
+            && !name.contains("-.")
+            && name.parse::<Ipv4Addr>().is_err()
+            && !name.starts_with("xn--")
+            && !name.starts_with("sthree-")
+            && !name.ends_with("-s3alias")
+    }
+
+    pub fn validate_aws_arn(arn: &str) -> bool {
+        parse_arn_service(arn).is_some()
+    }
+
+    pub fn validate_aws_arn_for_service(arn: &str, service: &str) -> bool {
+        parse_arn_service(arn) == Some(service)
+    }
+}
+
+fn parse_arn_service(arn: &str) -> Option<&str> {
+    let mut parts = arn.splitn(6, ':');
+    let (prefix, partition, service, region, account, resource) = (
+        parts.next()?,
+        parts.next()?,
+        parts.next()?,
+        parts.next()?,
+        parts.next()?,
+        parts.next()?,
+    );
+
+    let is_identifier = |value: &str| {
+        value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    };
+    let is_account = |value: &str| value.len() == 12 && value.chars().all(|c| c.is_ascii_digit());
+
+    let is_valid = prefix == "arn"
+        && !partition.is_empty()
+        && is_identifier(partition)
+        && !service.is_empty()
+        && is_identifier(service)
+        && is_identifier(region)
+        && (account.is_empty() || account == "aws" || is_account(account))
+        && !resource.is_empty();
+    is_valid.then_some(service)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_s3_bucket_name() {
+        assert!(ValidationMethods::validate_s3_bucket_name("my-bucket"));
+        assert!(ValidationMethods::validate_s3_bucket_name(
+            "logs.example.com"
+        ));
+        assert!(ValidationMethods::validate_s3_bucket_name("abc"));
+        assert!(ValidationMethods::validate_s3_bucket_name(&"a".repeat(63)));
+    }
+
+    #[test]
+    fn test_validate_s3_bucket_name_length() {
+        assert!(!ValidationMethods::validate_s3_bucket_name("ab"));
+        assert!(!ValidationMethods::validate_s3_bucket_name(&"a".repeat(64)));
+    }
+
+    #[test]
+    fn test_validate_s3_bucket_name_characters() {
+        assert!(!ValidationMethods::validate_s3_bucket_name("My-Bucket"));
+        assert!(!ValidationMethods::validate_s3_bucket_name("my_bucket"));
+        assert!(!ValidationMethods::validate_s3_bucket_name("my bucket"));
+    }
+
+    #[test]
+    fn test_validate_s3_bucket_name_edges() {
+        assert!(!ValidationMethods::validate_s3_bucket_name("-bucket"));
+        assert!(!ValidationMethods::validate_s3_bucket_name("bucket-"));
+        assert!(!ValidationMethods::validate_s3_bucket_name(".bucket"));
+        assert!(!ValidationMethods::validate_s3_bucket_name("bucket."));
+    }
+
+    #[test]
+    fn test_validate_s3_bucket_name_consecutive_separators() {
+        assert!(!ValidationMethods::validate_s3_bucket_name("my--bucket"));
+        assert!(!ValidationMethods::validate_s3_bucket_name("my..bucket"));
+        assert!(!ValidationMethods::validate_s3_bucket_name("my.-bucket"));
+    }
+
+    #[test]
+    fn test_validate_s3_bucket_name_ip_address() {
+        assert!(!ValidationMethods::validate_s3_bucket_name("192.168.5.4"));
+        assert!(ValidationMethods::validate_s3_bucket_name(
+            "192.168.5.4.logs"
+        ));
+    }
+
+    #[test]
+    fn test_validate_s3_bucket_name_reserved_affixes() {
+        assert!(!ValidationMethods::validate_s3_bucket_name("xn--bucket"));
+        assert!(!ValidationMethods::validate_s3_bucket_name("sthree-bucket"));
+        assert!(!ValidationMethods::validate_s3_bucket_name(
+            "bucket-s3alias"
+        ));
+    }
+
+    #[test]
+    fn test_validate_aws_arn() {
+        assert!(ValidationMethods::validate_aws_arn(
+            "arn:aws:iam::123456789012:user/Development/product_1234/*"
+        ));
+        assert!(ValidationMethods::validate_aws_arn(
+            "arn:aws:s3:::my_corporate_bucket/exampleobject.png"
+        ));
+        assert!(ValidationMethods::validate_aws_arn(
+            "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-0e9801d129EXAMPLE"
+        ));
+        assert!(ValidationMethods::validate_aws_arn(
+            "arn:aws:lambda:us-west-2:123456789012:function:my-function:1"
+        ));
+        assert!(ValidationMethods::validate_aws_arn(
+            "arn:aws-cn:sns:cn-north-1:123456789012:my-topic"
+        ));
+        assert!(ValidationMethods::validate_aws_arn(
+            "arn:aws:iam::aws:policy/AdministratorAccess"
+        ));
+
+        assert!(!ValidationMethods::validate_aws_arn(
+            "arm:aws:s3:::my-bucket"
+        ));
+        assert!(!ValidationMethods::validate_aws_arn("arn::s3:::my-bucket"));
+        assert!(!ValidationMethods::validate_aws_arn("arn:aws::::my-bucket"));
+        assert!(!ValidationMethods::validate_aws_arn("arn:aws:s3:::"));
+        assert!(!ValidationMethods::validate_aws_arn("arn:aws:s3:my-bucket"));
+        assert!(!ValidationMethods::validate_aws_arn(
+            "arn:aws:iam::12345:user/David"
+        ));
+        assert!(!ValidationMethods::validate_aws_arn(
+            "arn:aws:ec2:US-EAST-1:123456789012:vpc/vpc-1"
+        ));
+    }
+
+    #[test]
+    fn test_validate_aws_arn_for_service() {
+        let arn = "arn:aws:sqs:eu-west-1:123456789012:queue1";
+        assert!(ValidationMethods::validate_aws_arn_for_service(arn, "sqs"));
+        assert!(!ValidationMethods::validate_aws_arn_for_service(arn, "sns"));
+        assert!(!ValidationMethods::validate_aws_arn_for_service(
+            "arn:aws:sqs:eu-west-1:123456789012:",
+            "sqs"
+        ));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    proptest::proptest! {
+        #[test]
+        fn test_validate_s3_bucket_name_lowercase_alphanumeric(name in "[a-z0-9]{3,63}") {
+            proptest::prop_assert!(ValidationMethods::validate_s3_bucket_name(&name));
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_azure_resource_group_name(name: &str) -> bool {
+        (1..=90).contains(&name.chars().count())
+            && !name.ends_with('.')
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '(' | ')' | '-' | '.'))
+    }
+
+    pub fn validate_azure_storage_account_name(name: &str) -> bool {
+        (3..=24).contains(&name.len())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    }
+
+    pub fn validate_azure_subscription_id(id: &str) -> bool {
+        Self::validate_uuid(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_azure_resource_group_name() {
+        assert!(ValidationMethods::validate_azure_resource_group_name(
+            "rg-prod_01"
+        ));
+        assert!(ValidationMethods::validate_azure_resource_group_name(
+            "my.app(westeurope)"
+        ));
+        assert!(ValidationMethods::validate_azure_resource_group_name("a"));
+        assert!(ValidationMethods::validate_azure_resource_group_name(
+            &"a".repeat(90)
+        ));
+        assert!(!ValidationMethods::validate_azure_resource_group_name(
+            &"a".repeat(91)
+        ));
+        assert!(!ValidationMethods::validate_azure_resource_group_name(
+            "rg-prod."
+        ));
+        assert!(!ValidationMethods::validate_azure_resource_group_name(
+            "rg prod"
+        ));
+        assert!(!ValidationMethods::validate_azure_resource_group_name(
+            "rg/prod"
+        ));
+        assert!(!ValidationMethods::validate_azure_resource_group_name(""));
+    }
+
+    #[test]
+    fn test_validate_azure_storage_account_name() {
+        assert!(ValidationMethods::validate_azure_storage_account_name(
+            "mystorage01"
+        ));
+        assert!(ValidationMethods::validate_azure_storage_account_name(
+            "abc"
+        ));
+        assert!(!ValidationMethods::validate_azure_storage_account_name(
+            "ab"
+        ));
+        assert!(!ValidationMethods::validate_azure_storage_account_name(
+            &"a".repeat(25)
+        ));
+        assert!(!ValidationMethods::validate_azure_storage_account_name(
+            "MyStorage"
+        ));
+        assert!(!ValidationMethods::validate_azure_storage_account_name(
+            "my-storage"
+        ));
+    }
+
+    #[test]
+    fn test_validate_azure_subscription_id() {
+        assert!(ValidationMethods::validate_azure_subscription_id(
+            "0b1f6471-1bf0-4dda-aec3-cb9272f09590"
+        ));
+        assert!(!ValidationMethods::validate_azure_subscription_id(
+            "0b1f6471-1bf0-4dda-aec3"
+        ));
+    }
+}
@@ -0,0 +1,217 @@
+use super::country::is_iso_country_code;
+use crate::ValidationMethods;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub(crate) const FPS_REFERENCE_MAX_LENGTH: usize = 18;
+
+#[cfg(feature = "sample-sort-codes")]
+const SAMPLE_SORT_CODES: &str = include_str!("../../data/uk_sort_codes_sample.csv");
+
+impl ValidationMethods {
+    /// Validates a 9-digit US ABA routing transit number.
+    ///
+    /// The checksum follows the American Bankers Association specification
+    /// (ABA Key to Routing Numbers): `3 * (d1 + d4 + d7) + 7 * (d2 + d5 + d8)
+    /// + (d3 + d6 + d9)` must be divisible by 10. The first two digits must
+    /// fall in the Federal Reserve routing symbol range `01`–`32`.
+    pub fn validate_aba_routing(routing: &str) -> bool {
+        let digits: Vec<u32> = routing.chars().filter_map(|c| c.to_digit(10)).collect();
+        if routing.len() != 9 || digits.len() != 9 {
+            return false;
+        }
+
+        let prefix = digits[0] * 10 + digits[1];
+        let checksum = 3 * (digits[0] + digits[3] + digits[6])
+            + 7 * (digits[1] + digits[4] + digits[7])
+            + (digits[2] + digits[5] + digits[8]);
+
+        (1..=32).contains(&prefix) && checksum.is_multiple_of(10)
+    }
+
+    pub fn validate_payment_reference_fps(reference: &str) -> bool {
+        (1..=FPS_REFERENCE_MAX_LENGTH).contains(&reference.len())
+            && reference.trim() == reference
+            && reference.chars().all(is_fps_reference_char)
+    }
+
+    pub fn validate_bic(bic: &str) -> bool {
+        parse_bic(bic).is_some()
+    }
+
+    pub fn validate_bic_with_country_check(bic: &str) -> bool {
+        parse_bic(bic).is_some_and(|bic| is_iso_country_code(&bic[4..6]))
+    }
+
+    pub fn validate_uk_sort_code(sort_code: &str) -> bool {
+        normalize_sort_code(sort_code).is_some()
+    }
+
+    #[cfg(feature = "sample-sort-codes")]
+    /// Checks a sort code against the handful of codes bundled in
+    /// `data/uk_sort_codes_sample.csv`.
+    ///
+    /// The sample is for demos and tests only: the full Pay.UK directory is
+    /// licensed and not bundled, so `false` does not mean unregistered.
+    pub fn validate_uk_sort_code_in_sample(sort_code: &str) -> bool {
+        let sort_code = match normalize_sort_code(sort_code) {
+            Some(sort_code) => sort_code,
+            None => return false,
+        };
+
+        SAMPLE_SORT_CODES
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| line.split(',').next())
+            .any(|sample| sample.replace('-', "") == sort_code)
+    }
+}
+
+fn normalize_sort_code(sort_code: &str) -> Option<String> {
+    let digits = match sort_code.len() {
+        6 => String::from(sort_code),
+        8 if sort_code.as_bytes()[2] == b'-' && sort_code.as_bytes()[5] == b'-' => {
+            sort_code.replace('-', "")
+        }
+        _ => return None,
+    };
+
+    (digits.len() == 6 && digits.chars().all(|c| c.is_ascii_digit())).then_some(digits)
+}
+
+fn parse_bic(bic: &str) -> Option<String> {
+    let bic = bic.to_ascii_uppercase();
+    if !matches!(bic.len(), 8 | 11) || !bic.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    // Bank code and country code are letters; location and branch may be digits.
+    bic[..6]
+        .chars()
+        .all(|c| c.is_ascii_alphabetic())
+        .then_some(bic)
+}
+
+pub(crate) fn is_fps_reference_char(c: char) -> bool {
+    (' '..='~').contains(&c) && c != '|'
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_aba_routing() {
+        assert!(ValidationMethods::validate_aba_routing("021000021"));
+        assert!(ValidationMethods::validate_aba_routing("026009593"));
+        assert!(ValidationMethods::validate_aba_routing("121000248"));
+        assert!(ValidationMethods::validate_aba_routing("322271627"));
+        assert!(!ValidationMethods::validate_aba_routing("021000022"));
+        assert!(!ValidationMethods::validate_aba_routing("123456789"));
+        assert!(!ValidationMethods::validate_aba_routing("000000000"));
+        assert!(!ValidationMethods::validate_aba_routing("02100002"));
+        assert!(!ValidationMethods::validate_aba_routing("0210000210"));
+        assert!(!ValidationMethods::validate_aba_routing("02100002a"));
+    }
+
+    #[test]
+    fn test_validate_payment_reference_fps() {
+        assert!(ValidationMethods::validate_payment_reference_fps(
+            "INV-2024/001"
+        ));
+        assert!(ValidationMethods::validate_payment_reference_fps(
+            "RENT FLAT 4"
+        ));
+        assert!(ValidationMethods::validate_payment_reference_fps(
+            "ABCDEFGHIJKLMNOPQR"
+        ));
+        assert!(!ValidationMethods::validate_payment_reference_fps(
+            "ABCDEFGHIJKLMNOPQRS"
+        ));
+        assert!(!ValidationMethods::validate_payment_reference_fps(""));
+        assert!(!ValidationMethods::validate_payment_reference_fps(" RENT"));
+        assert!(!ValidationMethods::validate_payment_reference_fps("RENT "));
+        assert!(!ValidationMethods::validate_payment_reference_fps(
+            "RENT\r\nMAY"
+        ));
+        assert!(!ValidationMethods::validate_payment_reference_fps(
+            "RENT\tMAY"
+        ));
+        assert!(!ValidationMethods::validate_payment_reference_fps(
+            "RENT|MAY"
+        ));
+        assert!(!ValidationMethods::validate_payment_reference_fps("CAFÉ"));
+    }
+
+    #[test]
+    fn test_validate_uk_sort_code() {
+        assert!(ValidationMethods::validate_uk_sort_code("20-00-00"));
+        assert!(ValidationMethods::validate_uk_sort_code("200000"));
+        assert!(ValidationMethods::validate_uk_sort_code("60-83-71"));
+        assert!(!ValidationMethods::validate_uk_sort_code("20-00-0"));
+        assert!(!ValidationMethods::validate_uk_sort_code("2000000"));
+        assert!(!ValidationMethods::validate_uk_sort_code("20 00 00"));
+        assert!(!ValidationMethods::validate_uk_sort_code("2000-00"));
+        assert!(!ValidationMethods::validate_uk_sort_code("20-0000"));
+        assert!(!ValidationMethods::validate_uk_sort_code("--2000"));
+        assert!(!ValidationMethods::validate_uk_sort_code("20-00-0a"));
+        assert!(!ValidationMethods::validate_uk_sort_code(""));
+    }
+
+    #[cfg(feature = "sample-sort-codes")]
+    #[test]
+    fn test_validate_uk_sort_code_in_sample() {
+        assert!(ValidationMethods::validate_uk_sort_code_in_sample(
+            "20-00-00"
+        ));
+        assert!(ValidationMethods::validate_uk_sort_code_in_sample("040004"));
+        assert!(ValidationMethods::validate_uk_sort_code_in_sample(
+            "30-00-00"
+        ));
+        assert!(!ValidationMethods::validate_uk_sort_code_in_sample(
+            "99-99-99"
+        ));
+        assert!(!ValidationMethods::validate_uk_sort_code_in_sample(
+            "sort_code"
+        ));
+        assert!(!ValidationMethods::validate_uk_sort_code_in_sample(
+            "20-00-0"
+        ));
+    }
+
+    #[test]
+    fn test_validate_bic() {
+        assert!(ValidationMethods::validate_bic("DEUTDEFF"));
+        assert!(ValidationMethods::validate_bic("DEUTDEFF500"));
+        assert!(ValidationMethods::validate_bic("NEDSZAJJXXX"));
+        assert!(ValidationMethods::validate_bic("BOFAUS3N"));
+        assert!(ValidationMethods::validate_bic("deutdeff"));
+        assert!(ValidationMethods::validate_bic("BANKXXFF"));
+        assert!(!ValidationMethods::validate_bic("DEUT1EFF"));
+        assert!(!ValidationMethods::validate_bic("DEUTDEF"));
+        assert!(!ValidationMethods::validate_bic("DEUTDEFF50"));
+        assert!(!ValidationMethods::validate_bic("DEUTDEFF500X"));
+        assert!(!ValidationMethods::validate_bic("DEUT DEFF"));
+        assert!(!ValidationMethods::validate_bic("DEUTDEFFÉ"));
+        assert!(!ValidationMethods::validate_bic(""));
+    }
+
+    #[test]
+    fn test_validate_bic_with_country_check() {
+        assert!(ValidationMethods::validate_bic_with_country_check(
+            "DEUTDEFF"
+        ));
+        assert!(ValidationMethods::validate_bic_with_country_check(
+            "pkopplpwxxx"
+        ));
+        assert!(!ValidationMethods::validate_bic_with_country_check(
+            "BANKXXFF"
+        ));
+        assert!(!ValidationMethods::validate_bic_with_country_check(
+            "BARCUK22"
+        ));
+        assert!(!ValidationMethods::validate_bic_with_country_check(
+            "DEUT1EFF"
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+use super::checksum::{gtin, gtin_check_digit};
+use crate::ValidationMethods;
+use alloc::vec;
+use alloc::vec::Vec;
+
+impl ValidationMethods {
+    pub fn validate_ean13(barcode: &str) -> bool {
+        is_gtin(barcode, 13)
+    }
+
+    pub fn validate_ean8(barcode: &str) -> bool {
+        is_gtin(barcode, 8)
+    }
+
+    pub fn validate_ean(barcode: &str) -> bool {
+        Self::validate_ean13(barcode) || Self::validate_ean8(barcode)
+    }
+
+    pub fn validate_upc_a(barcode: &str) -> bool {
+        is_gtin(barcode, 12)
+    }
+
+    /// Validates a UPC-E code: either the bare 6-digit compressed body
+    /// (number system 0) or the full 8-digit form with number system and
+    /// check digit.
+    ///
+    /// The code is expanded to UPC-A and must be the canonical compression of
+    /// that UPC-A, so non-canonical bodies such as `000003` are rejected. The
+    /// bare body carries no check digit of its own; for the 8-digit form the
+    /// check digit must match the one computed for the expanded UPC-A.
+    pub fn validate_upc_e(barcode: &str) -> bool {
+        let digits = match parse_digits(barcode) {
+            Some(digits) => digits,
+            None => return false,
+        };
+
+        let (number_system, body, check_digit) = match digits.len() {
+            6 => (0, &digits[..], None),
+            8 if digits[0] <= 1 => (digits[0], &digits[1..7], Some(digits[7])),
+            _ => return false,
+        };
+        let upc_a = expand_upc_e(number_system, body);
+
+        gtin(&upc_a)
+            && check_digit.is_none_or(|check_digit| check_digit == upc_a[11])
+            && compress_upc_a(&upc_a).is_some_and(|compressed| compressed == body)
+    }
+}
+
+fn parse_digits(barcode: &str) -> Option<Vec<u32>> {
+    barcode.chars().map(|c| c.to_digit(10)).collect()
+}
+
+fn is_gtin(barcode: &str, length: usize) -> bool {
+    match parse_digits(barcode) {
+        Some(digits) if digits.len() == length => gtin(&digits),
+        _ => false,
+    }
+}
+
+// Expands a zero-suppressed UPC-E body into its 12-digit UPC-A, computing
+// the check digit.
+fn expand_upc_e(number_system: u32, body: &[u32]) -> Vec<u32> {
+    let mut upc_a = Vec::with_capacity(12);
+    upc_a.push(number_system);
+    match body[5] {
+        0..=2 => {
+            upc_a.extend_from_slice(&[body[0], body[1], body[5], 0, 0, 0, 0]);
+            upc_a.extend_from_slice(&body[2..5]);
+        }
+        3 => {
+            upc_a.extend_from_slice(&body[..3]);
+            upc_a.extend_from_slice(&[0, 0, 0, 0, 0, body[3], body[4]]);
+        }
+        4 => {
+            upc_a.extend_from_slice(&body[..4]);
+            upc_a.extend_from_slice(&[0, 0, 0, 0, 0, body[4]]);
+        }
+        _ => {
+            upc_a.extend_from_slice(&body[..5]);
+            upc_a.extend_from_slice(&[0, 0, 0, 0, body[5]]);
+        }
+    }
+    upc_a.push(gtin_check_digit(&upc_a));
+    upc_a
+}
+
+// Zero-suppresses a UPC-A into its 6-digit UPC-E body, using the first rule
+// that applies, or `None` if the UPC-A has no UPC-E form.
+fn compress_upc_a(upc_a: &[u32]) -> Option<Vec<u32>> {
+    let (manufacturer, product) = (&upc_a[1..6], &upc_a[6..11]);
+    match (manufacturer, product) {
+        ([m0, m1, m2 @ 0..=2, 0, 0], [0, 0, p2, p3, p4]) => {
+            Some(vec![*m0, *m1, *p2, *p3, *p4, *m2])
+        }
+        ([m0, m1, m2, 0, 0], [0, 0, 0, p3, p4]) => Some(vec![*m0, *m1, *m2, *p3, *p4, 3]),
+        ([m0, m1, m2, m3, 0], [0, 0, 0, 0, p4]) => Some(vec![*m0, *m1, *m2, *m3, *p4, 4]),
+        ([m0, m1, m2, m3, m4], [0, 0, 0, 0, p4 @ 5..=9]) => {
+            Some(vec![*m0, *m1, *m2, *m3, *m4, *p4])
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_ean13() {
+        assert!(ValidationMethods::validate_ean13("4006381333931"));
+        assert!(ValidationMethods::validate_ean13("5901234123457"));
+        assert!(ValidationMethods::validate_ean13("9780306406157"));
+        assert!(!ValidationMethods::validate_ean13("4006381333932"));
+        assert!(!ValidationMethods::validate_ean13("400638133393"));
+        assert!(!ValidationMethods::validate_ean13("40063813339310"));
+        assert!(!ValidationMethods::validate_ean13("400638133393a"));
+        assert!(!ValidationMethods::validate_ean13("73513537"));
+    }
+
+    #[test]
+    fn test_validate_ean8() {
+        assert!(ValidationMethods::validate_ean8("73513537"));
+        assert!(ValidationMethods::validate_ean8("96385074"));
+        assert!(!ValidationMethods::validate_ean8("73513538"));
+        assert!(!ValidationMethods::validate_ean8("7351353"));
+        assert!(!ValidationMethods::validate_ean8("4006381333931"));
+    }
+
+    #[test]
+    fn test_validate_ean() {
+        assert!(ValidationMethods::validate_ean("4006381333931"));
+        assert!(ValidationMethods::validate_ean("73513537"));
+        assert!(!ValidationMethods::validate_ean("036000291452"));
+        assert!(!ValidationMethods::validate_ean("4006381333932"));
+        assert!(!ValidationMethods::validate_ean(""));
+    }
+
+    #[test]
+    fn test_validate_upc_a() {
+        assert!(ValidationMethods::validate_upc_a("036000291452"));
+        assert!(ValidationMethods::validate_upc_a("012345678905"));
+        assert!(ValidationMethods::validate_upc_a("042100005264"));
+        assert!(!ValidationMethods::validate_upc_a("036000291453"));
+        assert!(!ValidationMethods::validate_upc_a("036000291451"));
+        assert!(!ValidationMethods::validate_upc_a("03600029145"));
+        assert!(!ValidationMethods::validate_upc_a("0036000291452"));
+        assert!(!ValidationMethods::validate_upc_a("03600029145a"));
+    }
+
+    #[test]
+    fn test_validate_upc_e() {
+        assert!(ValidationMethods::validate_upc_e("04252614"));
+        assert!(ValidationMethods::validate_upc_e("01234565"));
+        assert!(ValidationMethods::validate_upc_e("00123457"));
+        assert!(ValidationMethods::validate_upc_e("425261"));
+        assert!(ValidationMethods::validate_upc_e("123456"));
+        assert!(!ValidationMethods::validate_upc_e("000003"));
+        assert!(!ValidationMethods::validate_upc_e("120003"));
+        assert!(!ValidationMethods::validate_upc_e("123004"));
+        assert!(!ValidationMethods::validate_upc_e("123405"));
+        assert!(!ValidationMethods::validate_upc_e("01200038"));
+        assert!(!ValidationMethods::validate_upc_e("04252615"));
+        assert!(!ValidationMethods::validate_upc_e("04252613"));
+        assert!(!ValidationMethods::validate_upc_e("24252614"));
+        assert!(!ValidationMethods::validate_upc_e("4252614"));
+        assert!(!ValidationMethods::validate_upc_e("42526a"));
+        assert!(!ValidationMethods::validate_upc_e(""));
+    }
+
+    #[test]
+    fn test_expand_and_compress_upc_e() {
+        use super::{compress_upc_a, expand_upc_e};
+
+        let upc_a = expand_upc_e(0, &[4, 2, 5, 2, 6, 1]);
+        assert_eq!(upc_a, [0, 4, 2, 1, 0, 0, 0, 0, 5, 2, 6, 4]);
+        assert_eq!(compress_upc_a(&upc_a), Some(vec![4, 2, 5, 2, 6, 1]));
+        assert_eq!(compress_upc_a(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 5]), None);
+    }
+}
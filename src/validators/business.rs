@@ -0,0 +1,122 @@
+use super::checksum::mod97_10;
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    /// Checks that a D-U-N-S number is 9 digits, ignoring hyphens and spaces.
+    ///
+    /// This is a structural check only: Dun & Bradstreet publishes no check
+    /// digit algorithm, so a well-formed number may still be unassigned.
+    pub fn validate_duns(duns: &str) -> bool {
+        duns_digit_count(duns) == Some(9)
+    }
+
+    /// Like [`validate_duns`](Self::validate_duns), but also accepts the
+    /// 13-digit D-U-N-S+4 form used to identify a location within a company.
+    pub fn validate_duns_lenient(duns: &str) -> bool {
+        matches!(duns_digit_count(duns), Some(9 | 13))
+    }
+
+    /// Validates a 20-character ISO 17442 Legal Entity Identifier: an
+    /// alphanumeric LOU prefix and entity code, then two check digits
+    /// verified with ISO 7064 MOD 97-10.
+    pub fn validate_lei(lei: &str) -> bool {
+        lei.len() == 20
+            && lei
+                .chars()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+            && lei[18..].chars().all(|c| c.is_ascii_digit())
+            && mod97_10(lei)
+    }
+
+    /// Like [`validate_lei`](Self::validate_lei), but also requires the
+    /// first four characters to be one of `prefixes`.
+    ///
+    /// No LOU list is bundled: GLEIF accredits new LOUs over time, so callers
+    /// pass the prefixes they accept, typically loaded from GLEIF's published
+    /// list.
+    pub fn validate_lei_with_lou_prefixes(lei: &str, prefixes: &[&str]) -> bool {
+        Self::validate_lei(lei) && prefixes.contains(&&lei[..4])
+    }
+}
+
+fn duns_digit_count(duns: &str) -> Option<usize> {
+    let mut count = 0;
+    for c in duns.chars().filter(|c| !matches!(c, '-' | ' ')) {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+        count += 1;
+    }
+    Some(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_lei() {
+        assert!(ValidationMethods::validate_lei("HWUPKR0MPOU8FGXBT394"));
+        assert!(ValidationMethods::validate_lei("506700GE1G29325QX363"));
+        assert!(ValidationMethods::validate_lei("7LTWFZYICNSX8D621K86"));
+        assert!(ValidationMethods::validate_lei("213800WSGIIZCXF1P572"));
+        assert!(!ValidationMethods::validate_lei("HWUPKR0MPOU8FGXBT395"));
+        assert!(!ValidationMethods::validate_lei("HWUPKR0MPOU8FGXBT349"));
+        assert!(!ValidationMethods::validate_lei("hwupkr0mpou8fgxbt394"));
+        assert!(!ValidationMethods::validate_lei("HWUPKR0MPOU8FGXBT39"));
+        assert!(!ValidationMethods::validate_lei("HWUPKR0MPOU8FGXBT3940"));
+        assert!(!ValidationMethods::validate_lei("HWUPKR0MPOU8FGXBT3É"));
+        assert!(!ValidationMethods::validate_lei("HWUPKR0MPOU8FGXBT€"));
+        assert!(!ValidationMethods::validate_lei(""));
+    }
+
+    #[test]
+    fn test_validate_lei_with_lou_prefixes() {
+        let prefixes = ["HWUP", "5493"];
+        assert!(ValidationMethods::validate_lei_with_lou_prefixes(
+            "HWUPKR0MPOU8FGXBT394",
+            &prefixes
+        ));
+        assert!(ValidationMethods::validate_lei_with_lou_prefixes(
+            "5493001KJTIIGC8Y1R12",
+            &prefixes
+        ));
+        assert!(!ValidationMethods::validate_lei_with_lou_prefixes(
+            "506700GE1G29325QX363",
+            &prefixes
+        ));
+        assert!(!ValidationMethods::validate_lei_with_lou_prefixes(
+            "HWUPKR0MPOU8FGXBT395",
+            &prefixes
+        ));
+        assert!(!ValidationMethods::validate_lei_with_lou_prefixes(
+            "HWUPKR0MPOU8FGXBT394",
+            &[]
+        ));
+        assert!(!ValidationMethods::validate_lei_with_lou_prefixes(
+            "", &prefixes
+        ));
+    }
+
+    #[test]
+    fn test_validate_duns() {
+        assert!(ValidationMethods::validate_duns("15-048-3782"));
+        assert!(ValidationMethods::validate_duns("150483782"));
+        assert!(ValidationMethods::validate_duns("150 483 782"));
+        assert!(!ValidationMethods::validate_duns("15048378"));
+        assert!(!ValidationMethods::validate_duns("1504837820"));
+        assert!(!ValidationMethods::validate_duns("1504837820001"));
+        assert!(!ValidationMethods::validate_duns("15-048-378a"));
+        assert!(!ValidationMethods::validate_duns(""));
+    }
+
+    #[test]
+    fn test_validate_duns_lenient() {
+        assert!(ValidationMethods::validate_duns_lenient("15-048-3782"));
+        assert!(ValidationMethods::validate_duns_lenient("150483782-0001"));
+        assert!(ValidationMethods::validate_duns_lenient("1504837820001"));
+        assert!(!ValidationMethods::validate_duns_lenient("15048378200011"));
+        assert!(!ValidationMethods::validate_duns_lenient("150483782000"));
+        assert!(!ValidationMethods::validate_duns_lenient("150483782-000a"));
+    }
+}
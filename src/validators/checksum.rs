@@ -0,0 +1,100 @@
+pub(crate) fn luhn(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| match index % 2 {
+            0 => digit,
+            _ if digit > 4 => digit * 2 - 9,
+            _ => digit * 2,
+        })
+        .sum();
+
+    !digits.is_empty() && sum.is_multiple_of(10)
+}
+
+// GTIN family (EAN-8, EAN-13, UPC-A, ISBN-13): weights 1 and 3 alternate
+// from the check digit leftwards.
+pub(crate) fn gtin(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| match index % 2 {
+            0 => digit,
+            _ => digit * 3,
+        })
+        .sum();
+
+    !digits.is_empty() && sum.is_multiple_of(10)
+}
+
+pub(crate) fn gtin_check_digit(body: &[u32]) -> u32 {
+    let sum: u32 = body
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| match index % 2 {
+            0 => digit * 3,
+            _ => digit,
+        })
+        .sum();
+
+    (10 - sum % 10) % 10
+}
+
+// ISO 7064 MOD 97-10 over an alphanumeric string, letters expanded to 10–35
+// (as used by IBAN and LEI).
+pub(crate) fn mod97_10(value: &str) -> bool {
+    let mut remainder = 0;
+    for c in value.chars() {
+        let digit = match c.to_digit(36) {
+            Some(digit) if !c.is_ascii_lowercase() => digit,
+            _ => return false,
+        };
+        remainder = match digit {
+            0..=9 => (remainder * 10 + digit) % 97,
+            _ => (remainder * 100 + digit) % 97,
+        };
+    }
+
+    !value.is_empty() && remainder == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_luhn() {
+        assert!(luhn(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 3]));
+        assert!(luhn(&[0]));
+        assert!(!luhn(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 0]));
+        assert!(!luhn(&[]));
+    }
+
+    #[test]
+    fn test_gtin() {
+        assert!(gtin(&[4, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3, 1]));
+        assert!(gtin(&[7, 3, 5, 1, 3, 5, 3, 7]));
+        assert!(!gtin(&[4, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3, 2]));
+        assert!(!gtin(&[]));
+    }
+
+    #[test]
+    fn test_mod97_10() {
+        assert!(mod97_10("HWUPKR0MPOU8FGXBT394"));
+        // BE71 0961 2345 6769 with the country and check moved to the end.
+        assert!(mod97_10("096123456769BE71"));
+        assert!(!mod97_10("HWUPKR0MPOU8FGXBT395"));
+        assert!(!mod97_10("hwupkr0mpou8fgxbt394"));
+        assert!(!mod97_10(""));
+    }
+
+    #[test]
+    fn test_gtin_check_digit() {
+        assert_eq!(gtin_check_digit(&[4, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3]), 1);
+        assert_eq!(gtin_check_digit(&[7, 3, 5, 1, 3, 5, 3]), 7);
+        assert_eq!(gtin_check_digit(&[9, 7, 8, 3, 1, 6, 1, 4, 8, 4, 1, 0]), 0);
+    }
+}
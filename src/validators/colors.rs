@@ -0,0 +1,387 @@
+use crate::ValidationMethods;
+use alloc::vec::Vec;
+
+const CSS_NAMED_COLORS: [&str; 148] = [
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
+const CSS_COLOR_KEYWORDS: [&str; 2] = ["currentcolor", "transparent"];
+
+impl ValidationMethods {
+    pub fn validate_html_color_name(color: &str) -> bool {
+        CSS_NAMED_COLORS
+            .binary_search(&color.to_ascii_lowercase().as_str())
+            .is_ok()
+    }
+
+    pub fn validate_hex_color(color: &str) -> bool {
+        match color.strip_prefix('#') {
+            Some(hex) => {
+                matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+            }
+            None => false,
+        }
+    }
+
+    pub fn validate_any_css_color(color: &str) -> bool {
+        let color = color.trim();
+
+        Self::validate_html_color_name(color)
+            || CSS_COLOR_KEYWORDS.contains(&color.to_ascii_lowercase().as_str())
+            || Self::validate_hex_color(color)
+            || is_rgb_function(color)
+            || is_hsl_function(color)
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn parse_rgb(color: &str) -> Option<[u8; 3]> {
+    let color = color.trim();
+
+    if let Some(hex) = color.strip_prefix('#') {
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+        return match hex.len() {
+            3 => {
+                let channels: Vec<u8> = hex.bytes().map(|digit| hex_digit(digit) * 17).collect();
+                Some([channels[0], channels[1], channels[2]])
+            }
+            6 => Some([
+                channel(&hex[0..2])?,
+                channel(&hex[2..4])?,
+                channel(&hex[4..6])?,
+            ]),
+            _ => None,
+        };
+    }
+
+    let channels: Vec<&str> = match css_function_args(color, &["rgb"]) {
+        Some((channels, None)) => channels,
+        Some((_, Some(_))) => return None,
+        None => color.split(',').map(str::trim).collect(),
+    };
+    if channels.len() != 3 {
+        return None;
+    }
+
+    let mut rgb = [0u8; 3];
+    for (slot, channel) in rgb.iter_mut().zip(channels) {
+        if channel.is_empty() || !channel.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        *slot = channel.parse().ok()?;
+    }
+    Some(rgb)
+}
+
+#[cfg(feature = "std")]
+fn hex_digit(digit: u8) -> u8 {
+    (digit as char).to_digit(16).unwrap_or(0) as u8
+}
+
+fn is_rgb_function(color: &str) -> bool {
+    match css_function_args(color, &["rgb", "rgba"]) {
+        Some((channels, alpha)) => {
+            channels.iter().all(|channel| is_rgb_channel(channel)) && alpha.is_none_or(is_alpha)
+        }
+        None => false,
+    }
+}
+
+fn is_hsl_function(color: &str) -> bool {
+    match css_function_args(color, &["hsl", "hsla"]) {
+        Some((components, alpha)) => {
+            is_hue(components[0])
+                && is_percentage(components[1])
+                && is_percentage(components[2])
+                && alpha.is_none_or(is_alpha)
+        }
+        None => false,
+    }
+}
+
+fn css_function_args<'a>(
+    color: &'a str,
+    names: &[&str],
+) -> Option<(Vec<&'a str>, Option<&'a str>)> {
+    let (name, rest) = color.split_once('(')?;
+    if !names
+        .iter()
+        .any(|candidate| candidate.eq_ignore_ascii_case(name))
+    {
+        return None;
+    }
+    let inner = rest.strip_suffix(')')?;
+
+    let (components, alpha): (Vec<&str>, Option<&str>) = if inner.contains(',') {
+        let mut parts: Vec<&str> = inner.split(',').map(str::trim).collect();
+        let alpha = if parts.len() == 4 { parts.pop() } else { None };
+        (parts, alpha)
+    } else {
+        let (main, alpha) = match inner.split_once('/') {
+            Some((main, alpha)) => (main, Some(alpha.trim())),
+            None => (inner, None),
+        };
+        (main.split_whitespace().collect(), alpha)
+    };
+
+    (components.len() == 3).then_some((components, alpha))
+}
+
+fn parse_number(value: &str) -> Option<f64> {
+    if value.is_empty()
+        || !value
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+'))
+    {
+        return None;
+    }
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+}
+
+fn is_percentage(value: &str) -> bool {
+    value
+        .strip_suffix('%')
+        .and_then(parse_number)
+        .is_some_and(|number| (0.0..=100.0).contains(&number))
+}
+
+fn is_rgb_channel(value: &str) -> bool {
+    is_percentage(value)
+        || parse_number(value).is_some_and(|number| (0.0..=255.0).contains(&number))
+}
+
+fn is_alpha(value: &str) -> bool {
+    is_percentage(value) || parse_number(value).is_some_and(|number| (0.0..=1.0).contains(&number))
+}
+
+fn is_hue(value: &str) -> bool {
+    parse_number(value.strip_suffix("deg").unwrap_or(value)).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_css_named_colors_sorted() {
+        assert!(CSS_NAMED_COLORS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_validate_html_color_name() {
+        assert!(ValidationMethods::validate_html_color_name("red"));
+        assert!(ValidationMethods::validate_html_color_name("steelblue"));
+        assert!(ValidationMethods::validate_html_color_name("rebeccapurple"));
+        assert!(ValidationMethods::validate_html_color_name("SteelBlue"));
+        assert!(!ValidationMethods::validate_html_color_name("notacolor"));
+        assert!(!ValidationMethods::validate_html_color_name("transparent"));
+        assert!(!ValidationMethods::validate_html_color_name(""));
+    }
+
+    #[test]
+    fn test_validate_hex_color() {
+        assert!(ValidationMethods::validate_hex_color("#fff"));
+        assert!(ValidationMethods::validate_hex_color("#FFFF"));
+        assert!(ValidationMethods::validate_hex_color("#4682b4"));
+        assert!(ValidationMethods::validate_hex_color("#4682b480"));
+        assert!(!ValidationMethods::validate_hex_color("4682b4"));
+        assert!(!ValidationMethods::validate_hex_color("#4682b"));
+        assert!(!ValidationMethods::validate_hex_color("#ggg"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_rgb() {
+        assert_eq!(parse_rgb("#ffffff"), Some([255, 255, 255]));
+        assert_eq!(parse_rgb("#0f0"), Some([0, 255, 0]));
+        assert_eq!(parse_rgb("rgb(70, 130, 180)"), Some([70, 130, 180]));
+        assert_eq!(parse_rgb("70,130,180"), Some([70, 130, 180]));
+        assert_eq!(parse_rgb("#ffffff80"), None);
+        assert_eq!(parse_rgb("rgb(256, 0, 0)"), None);
+        assert_eq!(parse_rgb("steelblue"), None);
+    }
+
+    #[test]
+    fn test_validate_any_css_color() {
+        assert!(ValidationMethods::validate_any_css_color("transparent"));
+        assert!(ValidationMethods::validate_any_css_color("Red"));
+        assert!(ValidationMethods::validate_any_css_color("#abc"));
+        assert!(ValidationMethods::validate_any_css_color("rgb(255, 0, 0)"));
+        assert!(ValidationMethods::validate_any_css_color(
+            "rgba(255, 0, 0, 0.5)"
+        ));
+        assert!(ValidationMethods::validate_any_css_color(
+            "rgb(100% 0% 0% / 50%)"
+        ));
+        assert!(ValidationMethods::validate_any_css_color(
+            "hsl(120, 100%, 50%)"
+        ));
+        assert!(ValidationMethods::validate_any_css_color(
+            "hsl(120deg 100% 50% / 0.3)"
+        ));
+
+        assert!(!ValidationMethods::validate_any_css_color("notacolor"));
+        assert!(!ValidationMethods::validate_any_css_color("rgb(256, 0, 0)"));
+        assert!(!ValidationMethods::validate_any_css_color("rgb(255, 0)"));
+        assert!(!ValidationMethods::validate_any_css_color("rgb(nan, 0, 0)"));
+        assert!(!ValidationMethods::validate_any_css_color(
+            "hsl(120, 100, 50)"
+        ));
+        assert!(!ValidationMethods::validate_any_css_color(
+            "rgba(255, 0, 0, 2)"
+        ));
+    }
+}
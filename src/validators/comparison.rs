@@ -0,0 +1,50 @@
+use alloc::string::String;
+
+pub fn changed_from_validator(original: String) -> impl Fn(&str) -> bool {
+    let original = original.trim().to_lowercase();
+    move |input| input.trim().to_lowercase() != original
+}
+
+pub fn changed_from_case_sensitive_validator(original: String) -> impl Fn(&str) -> bool {
+    move |input| input.trim() != original.trim()
+}
+
+pub fn placeholder_validator(placeholder: impl Into<String>) -> impl Fn(&str) -> bool {
+    let placeholder = placeholder.into().trim().to_lowercase();
+    move |input| input.trim().to_lowercase() != placeholder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_changed_from_validator() {
+        let validator = changed_from_validator("Alice".to_string());
+        assert!(!validator("Alice"));
+        assert!(!validator("alice"));
+        assert!(!validator("  ALICE "));
+        assert!(validator("Alicia"));
+    }
+
+    #[test]
+    fn test_changed_from_case_sensitive_validator() {
+        let validator = changed_from_case_sensitive_validator("Alice".to_string());
+        assert!(!validator("Alice"));
+        assert!(!validator(" Alice "));
+        assert!(validator("alice"));
+        assert!(validator("Alicia"));
+    }
+
+    #[test]
+    fn test_placeholder_validator() {
+        let validator = placeholder_validator("Enter your name here");
+        assert!(!validator("Enter your name here"));
+        assert!(!validator("enter YOUR name HERE"));
+        assert!(!validator("  Enter your name here\t"));
+        assert!(validator("Alice"));
+        assert!(validator("Enter your name"));
+        assert!(validator(""));
+    }
+}
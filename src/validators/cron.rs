@@ -0,0 +1,154 @@
+use crate::ValidationMethods;
+use alloc::vec::Vec;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+struct CronField {
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+    first_name_value: u32,
+}
+
+const SECOND: CronField = CronField::numeric(0, 59);
+const MINUTE: CronField = CronField::numeric(0, 59);
+const HOUR: CronField = CronField::numeric(0, 23);
+const DAY_OF_MONTH: CronField = CronField::numeric(1, 31);
+const MONTH: CronField = CronField {
+    min: 1,
+    max: 12,
+    names: &MONTH_NAMES,
+    first_name_value: 1,
+};
+const DAY_OF_WEEK: CronField = CronField {
+    min: 0,
+    max: 7,
+    names: &WEEKDAY_NAMES,
+    first_name_value: 0,
+};
+const YEAR: CronField = CronField::numeric(1970, 2099);
+
+impl CronField {
+    const fn numeric(min: u32, max: u32) -> Self {
+        Self {
+            min,
+            max,
+            names: &[],
+            first_name_value: 0,
+        }
+    }
+
+    fn is_valid(&self, field: &str) -> bool {
+        !field.is_empty() && field.split(',').all(|item| self.is_valid_item(item))
+    }
+
+    fn is_valid_item(&self, item: &str) -> bool {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (item, None),
+        };
+
+        if let Some(step) = step {
+            match step.parse::<u32>() {
+                Ok(step) if step >= 1 && step <= self.max => {}
+                _ => return false,
+            }
+        }
+
+        if range == "*" {
+            return true;
+        }
+
+        match range.split_once('-') {
+            Some((start, end)) => match (self.value(start), self.value(end)) {
+                (Some(start), Some(end)) => start <= end,
+                _ => false,
+            },
+            None => self.value(range).is_some(),
+        }
+    }
+
+    fn value(&self, token: &str) -> Option<u32> {
+        let value = if token.chars().all(|c| c.is_ascii_digit()) {
+            token.parse::<u32>().ok()?
+        } else {
+            let position = self
+                .names
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(token))?;
+            self.first_name_value + position as u32
+        };
+
+        (self.min..=self.max).contains(&value).then_some(value)
+    }
+}
+
+impl ValidationMethods {
+    pub fn validate_cron_expression(expression: &str) -> bool {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+
+        let layout: &[CronField] = match fields.len() {
+            5 => &[MINUTE, HOUR, DAY_OF_MONTH, MONTH, DAY_OF_WEEK],
+            6 => &[SECOND, MINUTE, HOUR, DAY_OF_MONTH, MONTH, DAY_OF_WEEK],
+            7 => &[SECOND, MINUTE, HOUR, DAY_OF_MONTH, MONTH, DAY_OF_WEEK, YEAR],
+            _ => return false,
+        };
+
+        layout
+            .iter()
+            .zip(fields)
+            .all(|(spec, field)| spec.is_valid(field))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_cron_expression() {
+        assert!(ValidationMethods::validate_cron_expression("0 * * * *"));
+        assert!(ValidationMethods::validate_cron_expression("*/15 * * * *"));
+        assert!(ValidationMethods::validate_cron_expression(
+            "0 9-17 * * MON-FRI"
+        ));
+        assert!(ValidationMethods::validate_cron_expression(
+            "0,30 8 1 jan,jul 0"
+        ));
+        assert!(ValidationMethods::validate_cron_expression("30 0 12 * * 7"));
+        assert!(ValidationMethods::validate_cron_expression(
+            "0 0 12 1 1 * 2030"
+        ));
+        assert!(ValidationMethods::validate_cron_expression(
+            "0-30/5 * * * *"
+        ));
+
+        assert!(!ValidationMethods::validate_cron_expression("60 * * * *"));
+        assert!(!ValidationMethods::validate_cron_expression("0 24 * * *"));
+        assert!(!ValidationMethods::validate_cron_expression("0 0 0 * *"));
+        assert!(!ValidationMethods::validate_cron_expression("0 0 * 13 *"));
+        assert!(!ValidationMethods::validate_cron_expression("0 0 * * 8"));
+        assert!(!ValidationMethods::validate_cron_expression("*/0 * * * *"));
+        assert!(!ValidationMethods::validate_cron_expression(
+            "30-10 * * * *"
+        ));
+        assert!(!ValidationMethods::validate_cron_expression(
+            "0 0 * * MONDAY"
+        ));
+        assert!(!ValidationMethods::validate_cron_expression("0,,5 * * * *"));
+        assert!(!ValidationMethods::validate_cron_expression("* * * *"));
+        assert!(!ValidationMethods::validate_cron_expression(
+            "* * * * * * * *"
+        ));
+        assert!(!ValidationMethods::validate_cron_expression(""));
+    }
+
+    #[test]
+    fn test_validate_cron_expression_impossible_date() {
+        // Each field is in range, so the schedule is accepted even though it never fires.
+        assert!(ValidationMethods::validate_cron_expression("0 0 31 2 *"));
+    }
+}
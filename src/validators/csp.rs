@@ -0,0 +1,297 @@
+use crate::ValidationMethods;
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// Directives whose value is a source list (CSP Level 3 fetch and navigation
+// directives), sorted for binary search.
+const CSP_SOURCE_LIST_DIRECTIVES: [&str; 20] = [
+    "base-uri",
+    "child-src",
+    "connect-src",
+    "default-src",
+    "fenced-frame-src",
+    "font-src",
+    "form-action",
+    "frame-ancestors",
+    "frame-src",
+    "img-src",
+    "manifest-src",
+    "media-src",
+    "object-src",
+    "prefetch-src",
+    "script-src",
+    "script-src-attr",
+    "script-src-elem",
+    "style-src",
+    "style-src-attr",
+    "style-src-elem",
+];
+
+const CSP_KEYWORD_SOURCES: [&str; 9] = [
+    "'self'",
+    "'unsafe-inline'",
+    "'unsafe-eval'",
+    "'strict-dynamic'",
+    "'unsafe-hashes'",
+    "'report-sample'",
+    "'unsafe-allow-redirects'",
+    "'wasm-unsafe-eval'",
+    "'inline-speculation-rules'",
+];
+
+const CSP_SANDBOX_TOKENS: [&str; 14] = [
+    "allow-downloads",
+    "allow-forms",
+    "allow-modals",
+    "allow-orientation-lock",
+    "allow-pointer-lock",
+    "allow-popups",
+    "allow-popups-to-escape-sandbox",
+    "allow-presentation",
+    "allow-same-origin",
+    "allow-scripts",
+    "allow-storage-access-by-user-activation",
+    "allow-top-navigation",
+    "allow-top-navigation-by-user-activation",
+    "allow-top-navigation-to-custom-protocols",
+];
+
+impl ValidationMethods {
+    pub fn validate_csp_directive(directive: &str) -> bool {
+        let directive = directive.trim_matches([' ', '\t']);
+        let (name, value) = match directive.split_once([' ', '\t']) {
+            Some((name, value)) => (name, value),
+            None => (directive, ""),
+        };
+        let name = name.to_ascii_lowercase();
+        let values: Vec<&str> = value
+            .split([' ', '\t'])
+            .filter(|value| !value.is_empty())
+            .collect();
+
+        if CSP_SOURCE_LIST_DIRECTIVES
+            .binary_search(&name.as_str())
+            .is_ok()
+        {
+            return is_source_list(&values);
+        }
+        match name.as_str() {
+            "upgrade-insecure-requests" | "block-all-mixed-content" => values.is_empty(),
+            "sandbox" => values
+                .iter()
+                .all(|token| CSP_SANDBOX_TOKENS.contains(&token.to_ascii_lowercase().as_str())),
+            "report-uri" => {
+                !values.is_empty() && values.iter().all(|uri| !uri.contains([',', ';']))
+            }
+            "report-to" => matches!(values.as_slice(), [group] if is_token(group)),
+            "require-trusted-types-for" => {
+                !values.is_empty() && values.iter().all(|value| *value == "'script'")
+            }
+            "trusted-types" => values.iter().all(|value| {
+                matches!(*value, "'none'" | "'allow-duplicates'" | "*") || is_token(value)
+            }),
+            _ => false,
+        }
+    }
+
+    pub fn validate_csp_policy(policy: &str) -> bool {
+        let mut seen = BTreeSet::new();
+        let mut directives = policy
+            .split(';')
+            .map(|directive| directive.trim_matches([' ', '\t']))
+            .filter(|directive| !directive.is_empty())
+            .peekable();
+
+        directives.peek().is_some()
+            && directives.all(|directive| {
+                let name: String = directive
+                    .split([' ', '\t'])
+                    .next()
+                    .unwrap_or_default()
+                    .to_ascii_lowercase();
+                // Browsers ignore repeated directives, which is almost always a mistake.
+                Self::validate_csp_directive(directive) && seen.insert(name)
+            })
+    }
+}
+
+fn is_source_list(sources: &[&str]) -> bool {
+    match sources {
+        [] => false,
+        ["'none'"] => true,
+        sources => sources.iter().all(|source| is_source_expression(source)),
+    }
+}
+
+fn is_source_expression(source: &str) -> bool {
+    let lower = source.to_ascii_lowercase();
+    if CSP_KEYWORD_SOURCES.contains(&lower.as_str()) || source == "*" {
+        return true;
+    }
+    if let Some(quoted) = lower.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+        let value = ["nonce-", "sha256-", "sha384-", "sha512-"]
+            .iter()
+            .find_map(|prefix| quoted.strip_prefix(prefix));
+        // base64 or base64url value, unpadded or padded.
+        return value.is_some_and(|value| {
+            let data = value.trim_end_matches('=');
+            !data.is_empty()
+                && value.len() - data.len() <= 2
+                && data
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '-' | '_'))
+        });
+    }
+    if let Some(scheme) = source.strip_suffix(':') {
+        return is_scheme(scheme);
+    }
+    is_host_source(source)
+}
+
+fn is_scheme(scheme: &str) -> bool {
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+// `[scheme://]host[:port][/path]` where host may start with `*.`.
+fn is_host_source(source: &str) -> bool {
+    let rest = match source.split_once("://") {
+        Some((scheme, rest)) if is_scheme(scheme) => rest,
+        Some(_) => return false,
+        None => source,
+    };
+    let (authority, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, ""),
+    };
+    let (host, port) = match authority.split_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (authority, None),
+    };
+    let host = host.strip_prefix("*.").unwrap_or(host);
+
+    let host_is_valid = host == "*"
+        || (!host.is_empty()
+            && host.split('.').all(|label| {
+                !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            }));
+    let port_is_valid = port.is_none_or(|port| {
+        port == "*" || (!port.is_empty() && port.chars().all(|c| c.is_ascii_digit()))
+    });
+    let path_is_valid = !path.contains([',', ';', '\'']);
+
+    host_is_valid && port_is_valid && path_is_valid
+}
+
+fn is_token(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-#=_/@.%".contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csp_source_list_directives_sorted() {
+        assert!(CSP_SOURCE_LIST_DIRECTIVES
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_validate_csp_directive() {
+        assert!(ValidationMethods::validate_csp_directive(
+            "script-src 'self' https://cdn.example.com"
+        ));
+        assert!(ValidationMethods::validate_csp_directive(
+            "default-src 'none'"
+        ));
+        assert!(ValidationMethods::validate_csp_directive(
+            "img-src 'self' data: *.example.com:443 https://example.com/images/"
+        ));
+        assert!(ValidationMethods::validate_csp_directive(
+            "script-src 'nonce-rAnd0m123' 'sha256-abc+/def=' 'strict-dynamic'"
+        ));
+        assert!(ValidationMethods::validate_csp_directive("Style-Src *"));
+        assert!(ValidationMethods::validate_csp_directive(
+            "upgrade-insecure-requests"
+        ));
+        assert!(ValidationMethods::validate_csp_directive(
+            "sandbox allow-scripts allow-forms"
+        ));
+        assert!(ValidationMethods::validate_csp_directive("sandbox"));
+        assert!(ValidationMethods::validate_csp_directive(
+            "report-uri /csp-report"
+        ));
+        assert!(ValidationMethods::validate_csp_directive(
+            "report-to csp-endpoint"
+        ));
+        assert!(ValidationMethods::validate_csp_directive(
+            "require-trusted-types-for 'script'"
+        ));
+    }
+
+    #[test]
+    fn test_validate_csp_directive_rejects_invalid() {
+        assert!(!ValidationMethods::validate_csp_directive(
+            "scripts-src 'self'"
+        ));
+        assert!(!ValidationMethods::validate_csp_directive("script-src"));
+        assert!(!ValidationMethods::validate_csp_directive(
+            "script-src 'self"
+        ));
+        assert!(!ValidationMethods::validate_csp_directive(
+            "script-src 'none' 'self'"
+        ));
+        assert!(!ValidationMethods::validate_csp_directive(
+            "script-src 'unsafe-everything'"
+        ));
+        assert!(!ValidationMethods::validate_csp_directive(
+            "script-src 'nonce-'"
+        ));
+        assert!(!ValidationMethods::validate_csp_directive(
+            "script-src 'nonce-a b'"
+        ));
+        assert!(!ValidationMethods::validate_csp_directive(
+            "img-src https://exa_mple.com"
+        ));
+        assert!(!ValidationMethods::validate_csp_directive(
+            "img-src example.com:port"
+        ));
+        assert!(!ValidationMethods::validate_csp_directive(
+            "img-src 1http://example.com"
+        ));
+        assert!(!ValidationMethods::validate_csp_directive(
+            "upgrade-insecure-requests 1"
+        ));
+        assert!(!ValidationMethods::validate_csp_directive(
+            "sandbox allow-everything"
+        ));
+        assert!(!ValidationMethods::validate_csp_directive("report-uri"));
+        assert!(!ValidationMethods::validate_csp_directive("report-to a b"));
+        assert!(!ValidationMethods::validate_csp_directive(""));
+    }
+
+    #[test]
+    fn test_validate_csp_policy() {
+        assert!(ValidationMethods::validate_csp_policy(
+            "default-src 'self'; script-src 'self' https://cdn.example.com; object-src 'none';"
+        ));
+        assert!(ValidationMethods::validate_csp_policy(
+            "upgrade-insecure-requests"
+        ));
+        assert!(!ValidationMethods::validate_csp_policy(
+            "default-src 'self'; script-src 'self'; script-src https:"
+        ));
+        assert!(!ValidationMethods::validate_csp_policy(
+            "default-src 'self'; bogus-src *"
+        ));
+        assert!(!ValidationMethods::validate_csp_policy(" ; "));
+        assert!(!ValidationMethods::validate_csp_policy(""));
+    }
+}
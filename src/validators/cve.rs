@@ -0,0 +1,75 @@
+#[cfg(feature = "std")]
+use crate::SystemClock;
+use crate::{Clock, ValidationMethods};
+
+impl ValidationMethods {
+    #[cfg(feature = "std")]
+    pub fn validate_cve_id(id: &str) -> bool {
+        Self::validate_cve_id_with_clock(id, &SystemClock)
+    }
+
+    pub fn validate_cve_id_with_clock(id: &str, clock: &impl Clock) -> bool {
+        let (prefix, rest) = match id.split_at_checked(4) {
+            Some(parts) => parts,
+            None => return false,
+        };
+        let (year, sequence) = match rest.split_once('-') {
+            Some(parts) if prefix.eq_ignore_ascii_case("CVE-") => parts,
+            _ => return false,
+        };
+        let is_digits = |value: &str| value.chars().all(|c| c.is_ascii_digit());
+
+        let year_is_valid = year.len() == 4
+            && is_digits(year)
+            && year
+                .parse::<i32>()
+                .is_ok_and(|year| (1999..=clock.today().year + 1).contains(&year));
+        // Since 2014 sequences may exceed four digits, but only without padding.
+        let sequence_is_valid = match sequence.len() {
+            4 => is_digits(sequence),
+            5.. => is_digits(sequence) && !sequence.starts_with('0'),
+            _ => false,
+        };
+
+        year_is_valid && sequence_is_valid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, FixedClock, ValidationMethods};
+
+    const CLOCK: FixedClock = FixedClock(Date {
+        year: 2025,
+        month: 6,
+        day: 15,
+    });
+
+    #[test]
+    fn test_validate_cve_id_with_clock() {
+        let valid = |id| ValidationMethods::validate_cve_id_with_clock(id, &CLOCK);
+        assert!(valid("CVE-2021-44228"));
+        assert!(valid("cve-1999-0001"));
+        assert!(valid("CVE-2021-1234567"));
+        assert!(valid("CVE-2014-0160"));
+        assert!(valid("CVE-2026-0001"));
+        assert!(!valid("CVE-2027-0001"));
+        assert!(!valid("CVE-1998-0001"));
+        assert!(!valid("CVE-2021-123"));
+        assert!(!valid("CVE-2021-01234"));
+        assert!(!valid("CVE-21-44228"));
+        assert!(!valid("CVE-2021-4422a"));
+        assert!(!valid("CVE 2021 44228"));
+        assert!(!valid("CAN-2021-44228"));
+        assert!(!valid("2021-44228"));
+        assert!(!valid("CVE-2021-"));
+        assert!(!valid(""));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_validate_cve_id() {
+        assert!(ValidationMethods::validate_cve_id("CVE-2021-44228"));
+        assert!(!ValidationMethods::validate_cve_id("CVE-1998-0001"));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::validators::is_http_token;
+use crate::ValidationMethods;
+
+const URI_SYMBOLS: &str = "-._~!$&'()*+,;=:@/?";
+
+impl ValidationMethods {
+    pub fn validate_data_uri(uri: &str) -> bool {
+        let rest = match uri.get(..5) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("data:") => &uri[5..],
+            _ => return false,
+        };
+        let (header, data) = match rest.split_once(',') {
+            Some(parts) => parts,
+            None => return false,
+        };
+
+        let split = header.len().saturating_sub(7);
+        let (media_type, is_base64) = match header.get(split..) {
+            Some(suffix) if suffix.eq_ignore_ascii_case(";base64") => (&header[..split], true),
+            _ => (header, false),
+        };
+
+        let is_data = if is_base64 {
+            data.is_empty() || Self::validate_base64(data)
+        } else {
+            is_percent_encoded(data)
+        };
+        is_media_type(media_type) && is_data
+    }
+}
+
+// An empty media type stands for the default `text/plain;charset=US-ASCII`.
+fn is_media_type(media_type: &str) -> bool {
+    let mut parts = media_type.split(';');
+    let essence = parts.next().unwrap_or_default();
+
+    let is_essence = essence.is_empty()
+        || essence
+            .split_once('/')
+            .is_some_and(|(kind, subtype)| is_http_token(kind) && is_http_token(subtype));
+
+    is_essence
+        && parts.all(|parameter| {
+            parameter
+                .split_once('=')
+                .is_some_and(|(name, value)| is_http_token(name) && is_http_token(value))
+        })
+}
+
+fn is_percent_encoded(data: &str) -> bool {
+    let bytes = data.as_bytes();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if byte == b'%' {
+            let is_escape = bytes
+                .get(index + 1..index + 3)
+                .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+            if !is_escape {
+                return false;
+            }
+            index += 3;
+        } else if byte.is_ascii_alphanumeric() || URI_SYMBOLS.as_bytes().contains(&byte) {
+            index += 1;
+        } else {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_data_uri() {
+        assert!(ValidationMethods::validate_data_uri(
+            "data:text/plain,Hello%2C%20World%21"
+        ));
+        assert!(ValidationMethods::validate_data_uri(
+            "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAAB"
+        ));
+        assert!(ValidationMethods::validate_data_uri("data:,Hello"));
+        assert!(ValidationMethods::validate_data_uri(
+            "data:text/html;charset=utf-8,%3Ch1%3EHi%3C%2Fh1%3E"
+        ));
+        assert!(ValidationMethods::validate_data_uri(
+            "DATA:;charset=US-ASCII;BASE64,SGk="
+        ));
+        assert!(ValidationMethods::validate_data_uri("data:,"));
+
+        assert!(!ValidationMethods::validate_data_uri("text/plain,Hello"));
+        assert!(!ValidationMethods::validate_data_uri("data:text/plain"));
+        assert!(!ValidationMethods::validate_data_uri(
+            "data:image/png;base64,iVBOR!"
+        ));
+        assert!(!ValidationMethods::validate_data_uri(
+            "data:image/png;base64,iVBOR"
+        ));
+        assert!(!ValidationMethods::validate_data_uri("data:text,Hello"));
+        assert!(!ValidationMethods::validate_data_uri(
+            "data:text/plain;charset,Hi"
+        ));
+        assert!(!ValidationMethods::validate_data_uri("data:,Hello World"));
+        assert!(!ValidationMethods::validate_data_uri("data:,100%"));
+        assert!(!ValidationMethods::validate_data_uri("data:,%zz"));
+        assert!(!ValidationMethods::validate_data_uri("data:,żółw"));
+        assert!(!ValidationMethods::validate_data_uri("data:żółwabc,Hi"));
+    }
+}
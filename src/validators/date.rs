@@ -0,0 +1,93 @@
+#[cfg(feature = "std")]
+use crate::SystemClock;
+use crate::{Clock, Date, ValidationMethods};
+
+impl ValidationMethods {
+    #[cfg(feature = "std")]
+    pub fn validate_not_future_date(date: &str) -> bool {
+        Self::validate_not_future_date_with_clock(date, &SystemClock)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn validate_not_past_date(date: &str) -> bool {
+        Self::validate_not_past_date_with_clock(date, &SystemClock)
+    }
+
+    pub fn validate_not_future_date_with_clock(date: &str, clock: &impl Clock) -> bool {
+        Date::parse_iso(date).is_some_and(|date| date <= clock.today())
+    }
+
+    pub fn validate_not_past_date_with_clock(date: &str, clock: &impl Clock) -> bool {
+        Date::parse_iso(date).is_some_and(|date| date >= clock.today())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, FixedClock, ValidationMethods};
+
+    const CLOCK: FixedClock = FixedClock(Date {
+        year: 2025,
+        month: 3,
+        day: 15,
+    });
+
+    #[test]
+    fn test_validate_not_future_date() {
+        assert!(ValidationMethods::validate_not_future_date_with_clock(
+            "2025-03-15",
+            &CLOCK
+        ));
+        assert!(ValidationMethods::validate_not_future_date_with_clock(
+            "2025-03-14",
+            &CLOCK
+        ));
+        assert!(ValidationMethods::validate_not_future_date_with_clock(
+            "1990-12-31",
+            &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_not_future_date_with_clock(
+            "2025-03-16",
+            &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_not_future_date_with_clock(
+            "2025-02-30",
+            &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_not_future_date_with_clock(
+            "yesterday",
+            &CLOCK
+        ));
+    }
+
+    #[test]
+    fn test_validate_not_past_date() {
+        assert!(ValidationMethods::validate_not_past_date_with_clock(
+            "2025-03-15",
+            &CLOCK
+        ));
+        assert!(ValidationMethods::validate_not_past_date_with_clock(
+            "2025-03-16",
+            &CLOCK
+        ));
+        assert!(ValidationMethods::validate_not_past_date_with_clock(
+            "2026-01-01",
+            &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_not_past_date_with_clock(
+            "2025-03-14",
+            &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_not_past_date_with_clock(
+            "2025-13-01",
+            &CLOCK
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_validate_dates_system_clock() {
+        assert!(ValidationMethods::validate_not_future_date("2000-01-01"));
+        assert!(ValidationMethods::validate_not_past_date("2999-01-01"));
+    }
+}
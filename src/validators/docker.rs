@@ -0,0 +1,176 @@
+use crate::ValidationMethods;
+
+const DOCKER_NAME_MAX_LENGTH: usize = 255;
+const DOCKER_TAG_MAX_LENGTH: usize = 128;
+
+impl ValidationMethods {
+    pub fn validate_docker_image_name(reference: &str) -> bool {
+        let (reference, digest) = match reference.split_once('@') {
+            Some((reference, digest)) => (reference, Some(digest)),
+            None => (reference, None),
+        };
+        let (name, tag) = match reference.rsplit_once(':') {
+            Some((name, tag)) if !tag.contains('/') => (name, Some(tag)),
+            _ => (reference, None),
+        };
+
+        is_image_name(name) && tag.is_none_or(is_docker_tag) && digest.is_none_or(is_digest)
+    }
+
+    pub fn validate_docker_tag(tag: &str) -> bool {
+        is_docker_tag(tag)
+    }
+
+    pub fn validate_docker_tag_no_latest(tag: &str) -> bool {
+        is_docker_tag(tag) && tag != "latest"
+    }
+}
+
+fn is_image_name(name: &str) -> bool {
+    if name.len() > DOCKER_NAME_MAX_LENGTH {
+        return false;
+    }
+
+    let path = match name.split_once('/') {
+        Some((domain, path)) if domain.contains(['.', ':']) || domain == "localhost" => {
+            if !is_registry_host(domain) {
+                return false;
+            }
+            path
+        }
+        _ => name,
+    };
+
+    path.split('/').all(is_path_component)
+}
+
+fn is_registry_host(domain: &str) -> bool {
+    let (host, port) = match domain.split_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (domain, None),
+    };
+
+    host.split('.').all(|component| {
+        !component.is_empty()
+            && !component.starts_with('-')
+            && !component.ends_with('-')
+            && component
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    }) && port.is_none_or(|port| {
+        !port.is_empty() && port.len() <= 5 && port.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+fn is_path_component(component: &str) -> bool {
+    let is_alphanumeric = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+    if !component.starts_with(is_alphanumeric) || !component.ends_with(is_alphanumeric) {
+        return false;
+    }
+
+    component
+        .split(is_alphanumeric)
+        .filter(|separator| !separator.is_empty())
+        .all(|separator| {
+            matches!(separator, "." | "_" | "__") || separator.chars().all(|c| c == '-')
+        })
+}
+
+fn is_docker_tag(tag: &str) -> bool {
+    (1..=DOCKER_TAG_MAX_LENGTH).contains(&tag.len())
+        && tag.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+fn is_digest(digest: &str) -> bool {
+    match digest.split_once(':') {
+        Some(("sha256", hex)) => hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        Some(("sha512", hex)) => hex.len() == 128 && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_docker_image_name() {
+        assert!(ValidationMethods::validate_docker_image_name("nginx"));
+        assert!(ValidationMethods::validate_docker_image_name(
+            "nginx:latest"
+        ));
+        assert!(ValidationMethods::validate_docker_image_name(
+            "registry.example.com:5000/myapp:v1.2"
+        ));
+        assert!(ValidationMethods::validate_docker_image_name(
+            "localhost/team/my_app__v2:1.0-rc.1"
+        ));
+        assert!(ValidationMethods::validate_docker_image_name(
+            "library/ubuntu@sha256:45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2"
+        ));
+        assert!(ValidationMethods::validate_docker_image_name(
+            "ghcr.io/org/app:main@sha256:45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2"
+        ));
+
+        assert!(!ValidationMethods::validate_docker_image_name(
+            "invalid_UPPERCASE/name"
+        ));
+        assert!(!ValidationMethods::validate_docker_image_name(
+            "MyApp:latest"
+        ));
+        assert!(!ValidationMethods::validate_docker_image_name(&format!(
+            "nginx:{}",
+            "a".repeat(129)
+        )));
+        assert!(ValidationMethods::validate_docker_image_name(&format!(
+            "nginx:{}",
+            "a".repeat(128)
+        )));
+        assert!(!ValidationMethods::validate_docker_image_name("nginx:"));
+        assert!(!ValidationMethods::validate_docker_image_name(
+            "nginx:-latest"
+        ));
+        assert!(!ValidationMethods::validate_docker_image_name("my..app"));
+        assert!(!ValidationMethods::validate_docker_image_name("my___app"));
+        assert!(!ValidationMethods::validate_docker_image_name("-app"));
+        assert!(!ValidationMethods::validate_docker_image_name("team//app"));
+        assert!(!ValidationMethods::validate_docker_image_name(
+            "registry.example.com:port/app"
+        ));
+        assert!(!ValidationMethods::validate_docker_image_name(
+            "nginx@sha256:abc"
+        ));
+        assert!(!ValidationMethods::validate_docker_image_name(""));
+    }
+
+    #[test]
+    fn test_validate_docker_tag() {
+        assert!(ValidationMethods::validate_docker_tag("v1.2.3"));
+        assert!(ValidationMethods::validate_docker_tag("latest"));
+        assert!(ValidationMethods::validate_docker_tag("my_tag-1.0"));
+        assert!(ValidationMethods::validate_docker_tag("_internal"));
+        assert!(!ValidationMethods::validate_docker_tag("-leading"));
+        assert!(!ValidationMethods::validate_docker_tag(".leading"));
+        assert!(!ValidationMethods::validate_docker_tag("has:colon"));
+        assert!(!ValidationMethods::validate_docker_tag("has/slash"));
+        assert!(!ValidationMethods::validate_docker_tag(""));
+
+        let max_length = "a".repeat(128);
+        assert!(ValidationMethods::validate_docker_tag(&max_length));
+        let too_long = "a".repeat(129);
+        assert!(!ValidationMethods::validate_docker_tag(&too_long));
+    }
+
+    #[test]
+    fn test_validate_docker_tag_no_latest() {
+        assert!(ValidationMethods::validate_docker_tag_no_latest("v1.2.3"));
+        assert!(ValidationMethods::validate_docker_tag_no_latest(
+            "latest-1.0"
+        ));
+        assert!(!ValidationMethods::validate_docker_tag_no_latest("latest"));
+        assert!(!ValidationMethods::validate_docker_tag_no_latest("-bad"));
+    }
+}
@@ -0,0 +1,164 @@
+use super::lookup::sorted_lines_contain;
+use crate::email_utils::has_plus_tag;
+use crate::{html5_validators, ValidationMethods};
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const BUILTIN_DISPOSABLE_DOMAINS: &str = include_str!("../../data/disposable_email_domains.txt");
+
+impl ValidationMethods {
+    pub fn validate_email_no_plus_tag(email: &str) -> bool {
+        html5_validators::email(email) && !has_plus_tag(email)
+    }
+
+    pub fn validate_email_no_disposable(email: &str) -> bool {
+        html5_validators::email(email)
+            && email.rsplit_once('@').is_some_and(|(_, domain)| {
+                !is_blocked_domain(&domain.to_lowercase(), |candidate| {
+                    sorted_lines_contain(BUILTIN_DISPOSABLE_DOMAINS, candidate)
+                })
+            })
+    }
+}
+
+pub struct DisposableEmailValidator {
+    blocked_domains: BTreeSet<String>,
+}
+
+impl DisposableEmailValidator {
+    pub fn new(blocklist: &[&str]) -> Self {
+        Self {
+            blocked_domains: blocklist
+                .iter()
+                .map(|domain| domain.trim().to_lowercase())
+                .collect(),
+        }
+    }
+
+    pub fn with_builtin_blocklist() -> Self {
+        let domains: Vec<&str> = BUILTIN_DISPOSABLE_DOMAINS
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+
+        Self::new(&domains)
+    }
+
+    pub fn validate(&self, email: &str) -> bool {
+        let domain = match email.rsplit_once('@') {
+            Some((local, domain)) if !local.is_empty() && !domain.is_empty() => {
+                domain.to_lowercase()
+            }
+            _ => return false,
+        };
+
+        !is_blocked_domain(&domain, |candidate| {
+            self.blocked_domains.contains(candidate)
+        })
+    }
+}
+
+// Checks `domain` and each parent domain above the registrable level, so
+// `inbox.mailinator.com` matches a `mailinator.com` entry.
+fn is_blocked_domain(domain: &str, is_listed: impl Fn(&str) -> bool) -> bool {
+    let mut candidate = domain;
+    loop {
+        if is_listed(candidate) {
+            return true;
+        }
+        match candidate.split_once('.') {
+            Some((_, parent)) if parent.contains('.') => candidate = parent,
+            _ => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_email_no_plus_tag() {
+        assert!(ValidationMethods::validate_email_no_plus_tag(
+            "user@example.com"
+        ));
+        assert!(!ValidationMethods::validate_email_no_plus_tag(
+            "user+newsletter@example.com"
+        ));
+        assert!(!ValidationMethods::validate_email_no_plus_tag(
+            "user+@example.com"
+        ));
+        assert!(!ValidationMethods::validate_email_no_plus_tag(
+            "not-an-email"
+        ));
+    }
+
+    #[test]
+    fn test_disposable_email_validator() {
+        let validator = DisposableEmailValidator::new(&["Throwaway.example"]);
+        assert!(validator.validate("user@example.com"));
+        assert!(!validator.validate("user@throwaway.example"));
+        assert!(!validator.validate("user@THROWAWAY.example"));
+        assert!(!validator.validate("user@inbox.throwaway.example"));
+        assert!(!validator.validate("not-an-email"));
+    }
+
+    #[test]
+    fn test_disposable_email_validator_builtin_blocklist() {
+        let validator = DisposableEmailValidator::with_builtin_blocklist();
+        assert!(validator.validate("user@gmail.com"));
+        assert!(!validator.validate("user@mailinator.com"));
+        assert!(!validator.validate("user@guerrillamail.com"));
+        assert!(!validator.validate("User@YopMail.com"));
+    }
+
+    #[test]
+    fn test_validate_email_no_disposable() {
+        assert!(ValidationMethods::validate_email_no_disposable(
+            "user@gmail.com"
+        ));
+        assert!(ValidationMethods::validate_email_no_disposable(
+            "user@example.org"
+        ));
+        assert!(!ValidationMethods::validate_email_no_disposable(
+            "user@mailinator.com"
+        ));
+        assert!(!ValidationMethods::validate_email_no_disposable(
+            "User@YopMail.com"
+        ));
+        assert!(!ValidationMethods::validate_email_no_disposable(
+            "user@inbox.guerrillamail.com"
+        ));
+        assert!(!ValidationMethods::validate_email_no_disposable(
+            "user@0-mail.com"
+        ));
+        assert!(!ValidationMethods::validate_email_no_disposable(
+            "not-an-email"
+        ));
+    }
+
+    #[test]
+    fn test_builtin_blocklist_is_sorted() {
+        let domains: Vec<&str> = BUILTIN_DISPOSABLE_DOMAINS
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .collect();
+        assert!(domains.len() > 50_000);
+        assert!(domains.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(domains
+            .iter()
+            .all(|domain| sorted_lines_contain(BUILTIN_DISPOSABLE_DOMAINS, domain)));
+    }
+
+    #[test]
+    fn test_builtin_blocklist_is_normalized() {
+        for line in BUILTIN_DISPOSABLE_DOMAINS.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            assert_eq!(line, line.trim().to_lowercase(), "unnormalized entry");
+        }
+    }
+}
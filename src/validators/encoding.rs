@@ -0,0 +1,34 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_base64(input: &str) -> bool {
+        let data = input.trim_end_matches('=');
+        let padding = input.len() - data.len();
+
+        !input.is_empty()
+            && input.len().is_multiple_of(4)
+            && padding <= 2
+            && data
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_base64() {
+        assert!(ValidationMethods::validate_base64("SGVsbG8="));
+        assert!(ValidationMethods::validate_base64("SGVsbG8h"));
+        assert!(ValidationMethods::validate_base64("SGk="));
+        assert!(ValidationMethods::validate_base64("Zg=="));
+        assert!(ValidationMethods::validate_base64("a+/b"));
+        assert!(!ValidationMethods::validate_base64("SGVsbG8"));
+        assert!(!ValidationMethods::validate_base64("Z==="));
+        assert!(!ValidationMethods::validate_base64("SG=k"));
+        assert!(!ValidationMethods::validate_base64("SGVs bG8="));
+        assert!(!ValidationMethods::validate_base64(""));
+    }
+}
@@ -0,0 +1,66 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_env_var_name(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    pub fn validate_env_var_name_posix_strict(name: &str) -> bool {
+        Self::validate_env_var_name(name) && !name.chars().any(|c| c.is_ascii_lowercase())
+    }
+
+    pub fn validate_env_var_assignment(assignment: &str) -> bool {
+        assignment
+            .split_once('=')
+            .is_some_and(|(name, _)| Self::validate_env_var_name(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_env_var_name() {
+        assert!(ValidationMethods::validate_env_var_name("_PRIVATE_VAR"));
+        assert!(ValidationMethods::validate_env_var_name("VALID_NAME"));
+        assert!(ValidationMethods::validate_env_var_name("MIXED_Case"));
+        assert!(ValidationMethods::validate_env_var_name("path2"));
+        assert!(!ValidationMethods::validate_env_var_name("1INVALID"));
+        assert!(!ValidationMethods::validate_env_var_name("MY-VAR"));
+        assert!(!ValidationMethods::validate_env_var_name(""));
+    }
+
+    #[test]
+    fn test_validate_env_var_name_posix_strict() {
+        assert!(ValidationMethods::validate_env_var_name_posix_strict(
+            "_PRIVATE_VAR"
+        ));
+        assert!(ValidationMethods::validate_env_var_name_posix_strict(
+            "VALID_NAME2"
+        ));
+        assert!(!ValidationMethods::validate_env_var_name_posix_strict(
+            "MIXED_Case"
+        ));
+        assert!(!ValidationMethods::validate_env_var_name_posix_strict(
+            "1INVALID"
+        ));
+    }
+
+    #[test]
+    fn test_validate_env_var_assignment() {
+        assert!(ValidationMethods::validate_env_var_assignment(
+            "VALID_NAME=value"
+        ));
+        assert!(ValidationMethods::validate_env_var_assignment("EMPTY="));
+        assert!(ValidationMethods::validate_env_var_assignment(
+            "URL=https://example.com/?a=b"
+        ));
+        assert!(!ValidationMethods::validate_env_var_assignment(
+            "1INVALID=x"
+        ));
+        assert!(!ValidationMethods::validate_env_var_assignment("NO_VALUE"));
+        assert!(!ValidationMethods::validate_env_var_assignment("=value"));
+    }
+}
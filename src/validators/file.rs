@@ -0,0 +1,58 @@
+use crate::ValidationMethods;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const IMAGE_EXTENSIONS: [&str; 5] = ["jpg", "jpeg", "png", "gif", "webp"];
+
+impl ValidationMethods {
+    pub fn validate_image_extension(filename: &str) -> bool {
+        extension_allowlist_validator(&IMAGE_EXTENSIONS)(filename)
+    }
+}
+
+pub fn extension_allowlist_validator(allowed: &[&str]) -> impl Fn(&str) -> bool {
+    let allowed: Vec<String> = allowed
+        .iter()
+        .map(|extension| extension.trim_start_matches('.').to_lowercase())
+        .collect();
+
+    move |filename| {
+        let name = filename.rsplit(['/', '\\']).next().unwrap_or(filename);
+        let name = name.strip_prefix('.').unwrap_or(name);
+
+        match name.rsplit_once('.') {
+            Some((stem, extension)) if !stem.is_empty() && !stem.contains('.') => {
+                allowed.contains(&extension.to_lowercase())
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extension_allowlist_validator() {
+        let validator = extension_allowlist_validator(&["pdf", ".txt"]);
+        assert!(validator("report.pdf"));
+        assert!(validator("REPORT.PDF"));
+        assert!(validator("docs/notes.Txt"));
+        assert!(!validator("report.docx"));
+        assert!(!validator("report"));
+        assert!(!validator("report."));
+        assert!(!validator(".pdf"));
+        assert!(!validator("report.txt.pdf"));
+    }
+
+    #[test]
+    fn test_validate_image_extension() {
+        assert!(ValidationMethods::validate_image_extension("photo.jpg"));
+        assert!(ValidationMethods::validate_image_extension("photo.JPEG"));
+        assert!(ValidationMethods::validate_image_extension("banner.WebP"));
+        assert!(!ValidationMethods::validate_image_extension("file.png.exe"));
+        assert!(!ValidationMethods::validate_image_extension("file.exe.png"));
+        assert!(!ValidationMethods::validate_image_extension("photo"));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::ValidationMethods;
+
+const GCP_RESTRICTED_STRINGS: [&str; 4] = ["google", "null", "ssl", "undefined"];
+const GCP_SERVICE_ACCOUNT_DOMAIN: &str = ".iam.gserviceaccount.com";
+
+impl ValidationMethods {
+    pub fn validate_gcp_project_id(id: &str) -> bool {
+        is_gcp_name(id)
+            && !GCP_RESTRICTED_STRINGS
+                .iter()
+                .any(|restricted| id.contains(restricted))
+    }
+
+    pub fn validate_gcp_service_account_email(email: &str) -> bool {
+        match email.split_once('@') {
+            Some((name, domain)) => {
+                is_gcp_name(name)
+                    && domain
+                        .strip_suffix(GCP_SERVICE_ACCOUNT_DOMAIN)
+                        .is_some_and(Self::validate_gcp_project_id)
+            }
+            None => false,
+        }
+    }
+}
+
+fn is_gcp_name(name: &str) -> bool {
+    (6..=30).contains(&name.len())
+        && name.starts_with(|c: char| c.is_ascii_lowercase())
+        && !name.ends_with('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_gcp_project_id() {
+        assert!(ValidationMethods::validate_gcp_project_id("my-project-123"));
+        assert!(ValidationMethods::validate_gcp_project_id("abcdef"));
+        assert!(ValidationMethods::validate_gcp_project_id(&"a".repeat(30)));
+        assert!(!ValidationMethods::validate_gcp_project_id("abcde"));
+        assert!(!ValidationMethods::validate_gcp_project_id(&"a".repeat(31)));
+        assert!(!ValidationMethods::validate_gcp_project_id("1project"));
+        assert!(!ValidationMethods::validate_gcp_project_id("-project"));
+        assert!(!ValidationMethods::validate_gcp_project_id("project-"));
+        assert!(!ValidationMethods::validate_gcp_project_id("My-Project"));
+        assert!(!ValidationMethods::validate_gcp_project_id("my_project"));
+        assert!(!ValidationMethods::validate_gcp_project_id("my.project"));
+        assert!(!ValidationMethods::validate_gcp_project_id("my-google-app"));
+        assert!(!ValidationMethods::validate_gcp_project_id("ssl-checker"));
+        assert!(!ValidationMethods::validate_gcp_project_id("undefined-1"));
+    }
+
+    #[test]
+    fn test_validate_gcp_service_account_email() {
+        assert!(ValidationMethods::validate_gcp_service_account_email(
+            "deployer@my-project-123.iam.gserviceaccount.com"
+        ));
+        assert!(!ValidationMethods::validate_gcp_service_account_email(
+            "dep@my-project-123.iam.gserviceaccount.com"
+        ));
+        assert!(!ValidationMethods::validate_gcp_service_account_email(
+            "deployer@my_project.iam.gserviceaccount.com"
+        ));
+        assert!(!ValidationMethods::validate_gcp_service_account_email(
+            "deployer@my-project-123.gserviceaccount.com"
+        ));
+        assert!(!ValidationMethods::validate_gcp_service_account_email(
+            "deployer@my-project-123.iam.gserviceaccount.com.evil"
+        ));
+        assert!(!ValidationMethods::validate_gcp_service_account_email(
+            "my-project-123.iam.gserviceaccount.com"
+        ));
+    }
+}
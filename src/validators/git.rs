@@ -0,0 +1,135 @@
+use crate::ValidationMethods;
+
+const GIT_REF_FORBIDDEN_CHARS: [char; 7] = [' ', '~', '^', ':', '?', '*', '['];
+const GIT_SHA_FULL_LENGTH: usize = 40;
+const GIT_SHA_SHORT_LENGTHS: core::ops::RangeInclusive<usize> = 7..=12;
+
+impl ValidationMethods {
+    pub fn validate_git_branch_name(name: &str) -> bool {
+        !name.is_empty()
+            && name != "@"
+            && !name.contains("..")
+            && !name.contains("@{")
+            && !name.contains('\\')
+            && !name.ends_with('.')
+            && !name
+                .chars()
+                .any(|c| c.is_ascii_control() || GIT_REF_FORBIDDEN_CHARS.contains(&c))
+            && name.split('/').all(|component| {
+                !component.is_empty()
+                    && !component.starts_with('.')
+                    && !component.ends_with(".lock")
+            })
+    }
+
+    /// Accepts a full SHA-1 object name: exactly 40 lowercase hex digits.
+    pub fn validate_git_commit_sha_full(sha: &str) -> bool {
+        sha.len() == GIT_SHA_FULL_LENGTH && is_lowercase_hex(sha)
+    }
+
+    /// Accepts an abbreviated hash of 7 to 12 lowercase hex digits.
+    ///
+    /// Seven is git's default abbreviation (`core.abbrev`); git lengthens it
+    /// automatically in large repositories, so up to 12 digits are allowed.
+    pub fn validate_git_commit_sha_short(sha: &str) -> bool {
+        GIT_SHA_SHORT_LENGTHS.contains(&sha.len()) && is_lowercase_hex(sha)
+    }
+
+    pub fn validate_git_commit_sha(sha: &str) -> bool {
+        Self::validate_git_commit_sha_short(sha) || Self::validate_git_commit_sha_full(sha)
+    }
+}
+
+// Git always prints object names in lowercase, so both lengths share this rule.
+fn is_lowercase_hex(sha: &str) -> bool {
+    sha.chars()
+        .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_git_branch_name() {
+        assert!(ValidationMethods::validate_git_branch_name(
+            "feature/new-thing"
+        ));
+        assert!(ValidationMethods::validate_git_branch_name("main"));
+        assert!(ValidationMethods::validate_git_branch_name("release-1.2.3"));
+        assert!(ValidationMethods::validate_git_branch_name("user@host"));
+        assert!(!ValidationMethods::validate_git_branch_name(
+            "feature..branch"
+        ));
+        assert!(!ValidationMethods::validate_git_branch_name("trailing."));
+        assert!(!ValidationMethods::validate_git_branch_name("ok.lock"));
+        assert!(!ValidationMethods::validate_git_branch_name(
+            "feature/ok.lock/more"
+        ));
+        assert!(!ValidationMethods::validate_git_branch_name(".hidden"));
+        assert!(!ValidationMethods::validate_git_branch_name(
+            "feature/.hidden"
+        ));
+        assert!(!ValidationMethods::validate_git_branch_name("feature//x"));
+        assert!(!ValidationMethods::validate_git_branch_name("feature/"));
+        assert!(!ValidationMethods::validate_git_branch_name("has space"));
+        assert!(!ValidationMethods::validate_git_branch_name("back\\slash"));
+        assert!(!ValidationMethods::validate_git_branch_name("topic@{1}"));
+        assert!(!ValidationMethods::validate_git_branch_name("@"));
+        assert!(!ValidationMethods::validate_git_branch_name("tab\tname"));
+        for name in ["a?b", "a*b", "a[b", "a~b", "a^b", "a:b"] {
+            assert!(!ValidationMethods::validate_git_branch_name(name));
+        }
+        assert!(!ValidationMethods::validate_git_branch_name(""));
+    }
+
+    const FULL_SHA: &str = "8609a0f3c2b1d4e5f60718293a4b5c6d7e8f9012";
+
+    #[test]
+    fn test_validate_git_commit_sha_full() {
+        assert!(ValidationMethods::validate_git_commit_sha_full(FULL_SHA));
+        assert!(!ValidationMethods::validate_git_commit_sha_full(
+            &FULL_SHA.to_uppercase()
+        ));
+        assert!(!ValidationMethods::validate_git_commit_sha_full(
+            &FULL_SHA[..39]
+        ));
+        assert!(!ValidationMethods::validate_git_commit_sha_full(&format!(
+            "{}0",
+            FULL_SHA
+        )));
+        assert!(!ValidationMethods::validate_git_commit_sha_full(
+            &FULL_SHA.replace('8', "g")
+        ));
+    }
+
+    #[test]
+    fn test_validate_git_commit_sha_short() {
+        assert!(ValidationMethods::validate_git_commit_sha_short("8609a0f"));
+        assert!(!ValidationMethods::validate_git_commit_sha_short("8609A0F"));
+        assert!(ValidationMethods::validate_git_commit_sha_short(
+            "8609a0f3c2b1"
+        ));
+        assert!(!ValidationMethods::validate_git_commit_sha_short("8609a0"));
+        assert!(!ValidationMethods::validate_git_commit_sha_short(
+            "8609a0f3c2b1d"
+        ));
+        assert!(!ValidationMethods::validate_git_commit_sha_short("8609a0z"));
+    }
+
+    #[test]
+    fn test_validate_git_commit_sha() {
+        assert!(ValidationMethods::validate_git_commit_sha("8609a0f"));
+        assert!(ValidationMethods::validate_git_commit_sha(FULL_SHA));
+        assert!(!ValidationMethods::validate_git_commit_sha("8609A0F"));
+        assert!(!ValidationMethods::validate_git_commit_sha(
+            &FULL_SHA.to_uppercase()
+        ));
+        assert!(!ValidationMethods::validate_git_commit_sha(&format!(
+            "{}0",
+            FULL_SHA
+        )));
+        assert!(!ValidationMethods::validate_git_commit_sha("8609a0"));
+        assert!(!ValidationMethods::validate_git_commit_sha(&FULL_SHA[..20]));
+    }
+}
@@ -0,0 +1,100 @@
+use crate::ValidationMethods;
+
+const GITHUB_USERNAME_MAX_LENGTH: usize = 39;
+const GITHUB_REPO_NAME_MAX_LENGTH: usize = 100;
+
+impl ValidationMethods {
+    pub fn validate_github_username(username: &str) -> bool {
+        (1..=GITHUB_USERNAME_MAX_LENGTH).contains(&username.len())
+            && !username.starts_with('-')
+            && !username.ends_with('-')
+            && !username.contains("--")
+            && username
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    }
+
+    pub fn validate_github_repo_name(name: &str) -> bool {
+        (1..=GITHUB_REPO_NAME_MAX_LENGTH).contains(&name.len())
+            && name != "."
+            && name != ".."
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    }
+
+    pub fn validate_github_full_repo(full_name: &str) -> bool {
+        match full_name.split_once('/') {
+            Some((owner, repo)) => {
+                Self::validate_github_username(owner) && Self::validate_github_repo_name(repo)
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_github_username() {
+        assert!(ValidationMethods::validate_github_username("octocat"));
+        assert!(ValidationMethods::validate_github_username("torvalds"));
+        assert!(ValidationMethods::validate_github_username("vertyll"));
+        assert!(ValidationMethods::validate_github_username("rust-lang"));
+        assert!(ValidationMethods::validate_github_username("a"));
+        assert!(!ValidationMethods::validate_github_username("user--double"));
+        assert!(!ValidationMethods::validate_github_username("-leading"));
+        assert!(!ValidationMethods::validate_github_username("trailing-"));
+        assert!(!ValidationMethods::validate_github_username("under_score"));
+        assert!(!ValidationMethods::validate_github_username("dot.ted"));
+        assert!(!ValidationMethods::validate_github_username(""));
+
+        let max_length = "a".repeat(39);
+        assert!(ValidationMethods::validate_github_username(&max_length));
+        let too_long = "a".repeat(40);
+        assert!(!ValidationMethods::validate_github_username(&too_long));
+    }
+
+    #[test]
+    fn test_validate_github_repo_name() {
+        assert!(ValidationMethods::validate_github_repo_name(
+            "rust-user-input-validation"
+        ));
+        assert!(ValidationMethods::validate_github_repo_name(
+            "vertyll.github.io"
+        ));
+        assert!(ValidationMethods::validate_github_repo_name("my_repo"));
+        assert!(ValidationMethods::validate_github_repo_name(".github"));
+        assert!(!ValidationMethods::validate_github_repo_name("."));
+        assert!(!ValidationMethods::validate_github_repo_name(".."));
+        assert!(!ValidationMethods::validate_github_repo_name("my repo"));
+        assert!(!ValidationMethods::validate_github_repo_name("owner/repo"));
+        assert!(!ValidationMethods::validate_github_repo_name(""));
+
+        let max_length = "a".repeat(100);
+        assert!(ValidationMethods::validate_github_repo_name(&max_length));
+        let too_long = "a".repeat(101);
+        assert!(!ValidationMethods::validate_github_repo_name(&too_long));
+    }
+
+    #[test]
+    fn test_validate_github_full_repo() {
+        assert!(ValidationMethods::validate_github_full_repo(
+            "vertyll/rust-user-input-validation"
+        ));
+        assert!(ValidationMethods::validate_github_full_repo(
+            "rust-lang/rust"
+        ));
+        assert!(!ValidationMethods::validate_github_full_repo("rust-lang"));
+        assert!(!ValidationMethods::validate_github_full_repo(
+            "user--double/repo"
+        ));
+        assert!(!ValidationMethods::validate_github_full_repo("owner/.."));
+        assert!(!ValidationMethods::validate_github_full_repo(
+            "owner/repo/extra"
+        ));
+        assert!(!ValidationMethods::validate_github_full_repo("/repo"));
+    }
+}
@@ -0,0 +1,158 @@
+use crate::ValidationMethods;
+
+const GITLAB_PATH_MAX_LENGTH: usize = 255;
+
+// `TOP_LEVEL_ROUTES` from GitLab's `lib/gitlab/path_regex.rb`, minus the bare
+// `-` route that the character rules already exclude. Sorted for binary search.
+const GITLAB_RESERVED_NAMES: [&str; 38] = [
+    ".well-known",
+    "404.html",
+    "422.html",
+    "500.html",
+    "502.html",
+    "503.html",
+    "admin",
+    "api",
+    "apple-touch-icon.png",
+    "assets",
+    "dashboard",
+    "deploy.html",
+    "explore",
+    "favicon.ico",
+    "favicon.png",
+    "files",
+    "groups",
+    "health_check",
+    "help",
+    "import",
+    "jwt",
+    "login",
+    "oauth",
+    "profile",
+    "projects",
+    "public",
+    "robots.txt",
+    "s",
+    "search",
+    "sitemap",
+    "sitemap.xml",
+    "sitemap.xml.gz",
+    "slash-command-logo.png",
+    "snippets",
+    "unsubscribes",
+    "uploads",
+    "users",
+    "v2",
+];
+
+impl ValidationMethods {
+    pub fn validate_gitlab_project_path(path: &str) -> bool {
+        is_gitlab_path_segment(path) && !is_gitlab_reserved_name(path)
+    }
+
+    /// Reserved names are top-level routes, so they only clash in the first
+    /// segment: `admin/project` is rejected but `group/api` is fine.
+    pub fn validate_gitlab_full_path(path: &str) -> bool {
+        let mut segments = path.split('/');
+        segments
+            .next()
+            .is_some_and(Self::validate_gitlab_project_path)
+            && segments.all(is_gitlab_path_segment)
+    }
+}
+
+fn is_gitlab_path_segment(segment: &str) -> bool {
+    let lowercase = segment.to_ascii_lowercase();
+
+    segment.len() <= GITLAB_PATH_MAX_LENGTH
+        && segment.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        && !lowercase.ends_with(".git")
+        && !lowercase.ends_with(".atom")
+}
+
+fn is_gitlab_reserved_name(segment: &str) -> bool {
+    GITLAB_RESERVED_NAMES
+        .binary_search(&segment.to_ascii_lowercase().as_str())
+        .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gitlab_reserved_names_sorted() {
+        assert!(GITLAB_RESERVED_NAMES
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_validate_gitlab_project_path() {
+        assert!(ValidationMethods::validate_gitlab_project_path("gitlab"));
+        assert!(ValidationMethods::validate_gitlab_project_path(
+            "my-project.v2"
+        ));
+        assert!(ValidationMethods::validate_gitlab_project_path("_internal"));
+        assert!(ValidationMethods::validate_gitlab_project_path(
+            "Project_42"
+        ));
+        assert!(ValidationMethods::validate_gitlab_project_path(
+            "api-client"
+        ));
+        assert!(!ValidationMethods::validate_gitlab_project_path("admin"));
+        assert!(!ValidationMethods::validate_gitlab_project_path("API"));
+        assert!(!ValidationMethods::validate_gitlab_project_path("assets"));
+        assert!(!ValidationMethods::validate_gitlab_project_path(
+            "project.git"
+        ));
+        assert!(!ValidationMethods::validate_gitlab_project_path(
+            "feed.ATOM"
+        ));
+        assert!(!ValidationMethods::validate_gitlab_project_path("-project"));
+        assert!(!ValidationMethods::validate_gitlab_project_path(".project"));
+        assert!(!ValidationMethods::validate_gitlab_project_path(
+            "my project"
+        ));
+        assert!(!ValidationMethods::validate_gitlab_project_path(""));
+
+        let max_length = "a".repeat(GITLAB_PATH_MAX_LENGTH);
+        assert!(ValidationMethods::validate_gitlab_project_path(&max_length));
+        let too_long = "a".repeat(GITLAB_PATH_MAX_LENGTH + 1);
+        assert!(!ValidationMethods::validate_gitlab_project_path(&too_long));
+    }
+
+    #[test]
+    fn test_validate_gitlab_full_path() {
+        assert!(ValidationMethods::validate_gitlab_full_path(
+            "gitlab-org/gitlab"
+        ));
+        assert!(ValidationMethods::validate_gitlab_full_path(
+            "group/subgroup/project"
+        ));
+        assert!(ValidationMethods::validate_gitlab_full_path("project"));
+        assert!(ValidationMethods::validate_gitlab_full_path("group/api"));
+        assert!(ValidationMethods::validate_gitlab_full_path(
+            "group/admin/users"
+        ));
+        assert!(!ValidationMethods::validate_gitlab_full_path(
+            "group//project"
+        ));
+        assert!(!ValidationMethods::validate_gitlab_full_path(
+            "/group/project"
+        ));
+        assert!(!ValidationMethods::validate_gitlab_full_path(
+            "group/project/"
+        ));
+        assert!(!ValidationMethods::validate_gitlab_full_path(
+            "admin/project"
+        ));
+        assert!(!ValidationMethods::validate_gitlab_full_path("API/project"));
+        assert!(!ValidationMethods::validate_gitlab_full_path(
+            "group/repo.git"
+        ));
+    }
+}
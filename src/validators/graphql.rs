@@ -0,0 +1,226 @@
+use crate::ValidationMethods;
+use alloc::string::String;
+use core::iter::Peekable;
+use core::str::Chars;
+
+const GRAPHQL_RESERVED_NAMES: [&str; 3] = ["true", "false", "null"];
+const GRAPHQL_BUILTIN_SCALARS: [&str; 5] = ["Int", "Float", "String", "Boolean", "ID"];
+const GRAPHQL_OPERATION_KEYWORDS: [&str; 4] = ["query", "mutation", "subscription", "fragment"];
+const GRAPHQL_INTROSPECTION_FIELDS: [&str; 3] = ["__schema", "__type", "__typename"];
+
+impl ValidationMethods {
+    pub fn validate_graphql_name(name: &str) -> bool {
+        is_graphql_name(name) && !GRAPHQL_RESERVED_NAMES.contains(&name)
+    }
+
+    pub fn validate_graphql_enum_value(name: &str) -> bool {
+        Self::validate_graphql_name(name) && !GRAPHQL_BUILTIN_SCALARS.contains(&name)
+    }
+
+    /// Heuristic structural check for a GraphQL document, not a full parser.
+    ///
+    /// The document must open with `{` or an operation keyword, keep braces,
+    /// parentheses and brackets balanced, tokenize into valid names, numbers,
+    /// strings and punctuators, and only use `__`-prefixed names for the
+    /// introspection fields `__schema`, `__type` and `__typename`.
+    pub fn validate_graphql_query(query: &str) -> bool {
+        let mut chars = query.chars().peekable();
+        let mut depths = [0usize; 3];
+        let mut opened_selection = false;
+        let mut is_first_token = true;
+
+        while let Some(c) = chars.next() {
+            if c.is_whitespace() || c == ',' || c == '\u{feff}' {
+                continue;
+            }
+            if c == '#' {
+                while chars.next_if(|&c| c != '\n' && c != '\r').is_some() {}
+                continue;
+            }
+            let starts_document = match c {
+                '{' => true,
+                c if is_name_start(c) => {
+                    let name = take_name(c, &mut chars);
+                    if name.starts_with("__")
+                        && !GRAPHQL_INTROSPECTION_FIELDS.contains(&name.as_str())
+                    {
+                        return false;
+                    }
+                    GRAPHQL_OPERATION_KEYWORDS.contains(&name.as_str())
+                }
+                _ => false,
+            };
+            if is_first_token && !starts_document {
+                return false;
+            }
+            is_first_token = false;
+
+            let valid = match c {
+                '"' => skip_string(&mut chars),
+                '{' | '(' | '[' => {
+                    opened_selection |= c == '{';
+                    depths[bracket_index(c)] += 1;
+                    true
+                }
+                '}' | ')' | ']' => match depths[bracket_index(c)].checked_sub(1) {
+                    Some(depth) => {
+                        depths[bracket_index(c)] = depth;
+                        true
+                    }
+                    None => false,
+                },
+                '.' => chars.next_if_eq(&'.').is_some() && chars.next_if_eq(&'.').is_some(),
+                '-' | '0'..='9' => skip_number(&mut chars),
+                c => is_name_start(c) || "!$&:=@|".contains(c),
+            };
+            if !valid {
+                return false;
+            }
+        }
+
+        opened_selection && depths == [0; 3]
+    }
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+fn take_name(first: char, chars: &mut Peekable<Chars>) -> String {
+    let mut name = String::from(first);
+    while let Some(c) = chars.next_if(|&c| c.is_ascii_alphanumeric() || c == '_') {
+        name.push(c);
+    }
+    name
+}
+
+fn bracket_index(c: char) -> usize {
+    match c {
+        '{' | '}' => 0,
+        '(' | ')' => 1,
+        _ => 2,
+    }
+}
+
+// Consumes the rest of a string or block string after its opening quote.
+fn skip_string(chars: &mut Peekable<Chars>) -> bool {
+    if chars.next_if_eq(&'"').is_some() {
+        if chars.next_if_eq(&'"').is_none() {
+            // `""` is an empty string.
+            return true;
+        }
+        let mut quotes = 0;
+        for c in chars.by_ref() {
+            quotes = if c == '"' { quotes + 1 } else { 0 };
+            if quotes == 3 {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return true,
+            '\n' | '\r' => return false,
+            '\\' => {
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+// Consumes the rest of an Int or Float; a name may not follow directly.
+fn skip_number(chars: &mut Peekable<Chars>) -> bool {
+    while chars
+        .next_if(|&c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+        .is_some()
+    {}
+    !chars.peek().is_some_and(|&c| is_name_start(c))
+}
+
+pub(crate) fn is_graphql_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_graphql_name() {
+        assert!(ValidationMethods::validate_graphql_name("__typename"));
+        assert!(ValidationMethods::validate_graphql_name("User"));
+        assert!(ValidationMethods::validate_graphql_name("first_name2"));
+        assert!(ValidationMethods::validate_graphql_name("True"));
+        assert!(!ValidationMethods::validate_graphql_name("123invalid"));
+        assert!(!ValidationMethods::validate_graphql_name("true"));
+        assert!(!ValidationMethods::validate_graphql_name("null"));
+        assert!(!ValidationMethods::validate_graphql_name("first-name"));
+        assert!(!ValidationMethods::validate_graphql_name(""));
+    }
+
+    #[test]
+    fn test_validate_graphql_enum_value() {
+        assert!(ValidationMethods::validate_graphql_enum_value("ACTIVE"));
+        assert!(!ValidationMethods::validate_graphql_enum_value("String"));
+        assert!(!ValidationMethods::validate_graphql_enum_value("ID"));
+        assert!(!ValidationMethods::validate_graphql_enum_value("false"));
+        assert!(!ValidationMethods::validate_graphql_enum_value("9LIVES"));
+    }
+
+    #[test]
+    fn test_validate_graphql_query() {
+        assert!(ValidationMethods::validate_graphql_query(
+            "{ user { id name } }"
+        ));
+        assert!(ValidationMethods::validate_graphql_query(
+            "query GetUser($id: ID!) { user(id: $id) { id name __typename } }"
+        ));
+        assert!(ValidationMethods::validate_graphql_query(
+            "mutation { createUser(input: { name: \"Ann {\", age: 30, tags: [\"a\"] }) { id } }"
+        ));
+        assert!(ValidationMethods::validate_graphql_query(
+            "subscription OnEvent { event { ...EventFields } }\n\
+             fragment EventFields on Event { id score(min: -1.5e3) }"
+        ));
+        assert!(ValidationMethods::validate_graphql_query(
+            "# comment with { brace\nquery { __schema { types { name } } }"
+        ));
+        assert!(ValidationMethods::validate_graphql_query(
+            "query { search(text: \"\"\"multi\n\"line\" \"\"\") { id } }"
+        ));
+    }
+
+    #[test]
+    fn test_validate_graphql_query_rejects_malformed() {
+        assert!(!ValidationMethods::validate_graphql_query(
+            "{ user { id name }"
+        ));
+        assert!(!ValidationMethods::validate_graphql_query(
+            "{ user { id name } } }"
+        ));
+        assert!(!ValidationMethods::validate_graphql_query("} {"));
+        assert!(!ValidationMethods::validate_graphql_query(
+            "query { user(id: 1 { id } }"
+        ));
+        assert!(!ValidationMethods::validate_graphql_query("user { id }"));
+        assert!(!ValidationMethods::validate_graphql_query("queries { id }"));
+        assert!(!ValidationMethods::validate_graphql_query("query"));
+        assert!(!ValidationMethods::validate_graphql_query("{ 1user }"));
+        assert!(!ValidationMethods::validate_graphql_query("{ user-name }"));
+        assert!(!ValidationMethods::validate_graphql_query("{ __secret }"));
+        assert!(!ValidationMethods::validate_graphql_query(
+            "{ user(name: \"open) }"
+        ));
+        assert!(!ValidationMethods::validate_graphql_query("{ ..user }"));
+        assert!(!ValidationMethods::validate_graphql_query("{ user % }"));
+        assert!(!ValidationMethods::validate_graphql_query(""));
+    }
+}
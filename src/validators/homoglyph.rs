@@ -0,0 +1,126 @@
+use crate::ValidationMethods;
+use alloc::vec::Vec;
+
+// (lookalike, ASCII character it imitates), sorted by lookalike.
+const HOMOGLYPHS: [(char, char); 44] = [
+    ('\u{0391}', 'A'),
+    ('\u{0392}', 'B'),
+    ('\u{0395}', 'E'),
+    ('\u{0396}', 'Z'),
+    ('\u{0397}', 'H'),
+    ('\u{0399}', 'I'),
+    ('\u{039A}', 'K'),
+    ('\u{039C}', 'M'),
+    ('\u{039D}', 'N'),
+    ('\u{039F}', 'O'),
+    ('\u{03A1}', 'P'),
+    ('\u{03A4}', 'T'),
+    ('\u{03A5}', 'Y'),
+    ('\u{03A7}', 'X'),
+    ('\u{03B1}', 'a'),
+    ('\u{03BD}', 'v'),
+    ('\u{03BF}', 'o'),
+    ('\u{03C1}', 'p'),
+    ('\u{0405}', 'S'),
+    ('\u{0406}', 'I'),
+    ('\u{0408}', 'J'),
+    ('\u{0410}', 'A'),
+    ('\u{0412}', 'B'),
+    ('\u{0415}', 'E'),
+    ('\u{041A}', 'K'),
+    ('\u{041C}', 'M'),
+    ('\u{041D}', 'H'),
+    ('\u{041E}', 'O'),
+    ('\u{0420}', 'P'),
+    ('\u{0421}', 'C'),
+    ('\u{0422}', 'T'),
+    ('\u{0425}', 'X'),
+    ('\u{0430}', 'a'),
+    ('\u{0435}', 'e'),
+    ('\u{043E}', 'o'),
+    ('\u{0440}', 'p'),
+    ('\u{0441}', 'c'),
+    ('\u{0443}', 'y'),
+    ('\u{0445}', 'x'),
+    ('\u{0455}', 's'),
+    ('\u{0456}', 'i'),
+    ('\u{0458}', 'j'),
+    ('\u{0501}', 'd'),
+    ('\u{051B}', 'q'),
+];
+
+impl ValidationMethods {
+    pub fn validate_no_unicode_lookalikes(input: &str) -> bool {
+        !input.chars().any(|c| {
+            HOMOGLYPHS
+                .binary_search_by(|(lookalike, _)| lookalike.cmp(&c))
+                .is_ok()
+        })
+    }
+}
+
+pub struct HomoglyphDetector;
+
+impl HomoglyphDetector {
+    /// Returns a check rejecting any lookalike in `confusables` that plugs
+    /// into `Validator::with_validation`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(confusables: &[(char, char)]) -> impl Fn(&str) -> bool {
+        let mut lookalikes: Vec<char> = confusables
+            .iter()
+            .map(|&(lookalike, _)| lookalike)
+            .collect();
+        lookalikes.sort_unstable();
+        lookalikes.dedup();
+
+        move |input| !input.chars().any(|c| lookalikes.binary_search(&c).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_homoglyphs_sorted() {
+        assert!(HOMOGLYPHS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_validate_no_unicode_lookalikes() {
+        assert!(ValidationMethods::validate_no_unicode_lookalikes("paypal"));
+        assert!(ValidationMethods::validate_no_unicode_lookalikes("żółw"));
+        assert!(ValidationMethods::validate_no_unicode_lookalikes("Жизнь"));
+        assert!(!ValidationMethods::validate_no_unicode_lookalikes(
+            "p\u{0430}yp\u{0430}l"
+        ));
+        assert!(!ValidationMethods::validate_no_unicode_lookalikes(
+            "\u{0430}pple.com"
+        ));
+        assert!(!ValidationMethods::validate_no_unicode_lookalikes(
+            "g\u{03BF}\u{03BF}gle"
+        ));
+        assert!(!ValidationMethods::validate_no_unicode_lookalikes(
+            "\u{0421}\u{0410}\u{0422}"
+        ));
+    }
+
+    #[test]
+    fn test_homoglyph_detector() {
+        let detector = HomoglyphDetector::new(&[('\u{0430}', 'a'), ('0', 'O'), ('1', 'l')]);
+        assert!(detector("paypal"));
+        assert!(!detector("p\u{0430}ypal"));
+        assert!(!detector("G00GLE"));
+        assert!(!detector("pay1pal"));
+        assert!(detector("g\u{03BF}\u{03BF}gle"));
+    }
+
+    #[test]
+    fn test_homoglyph_detector_in_validator() {
+        let validator = crate::Validator::new(vec![ValidationMethods::not_empty])
+            .with_validation(HomoglyphDetector::new(&[('0', 'O')]));
+        assert!(validator.validate("GOOGLE"));
+        assert!(!validator.validate("G00GLE"));
+        assert!(!validator.validate(""));
+    }
+}
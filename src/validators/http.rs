@@ -0,0 +1,66 @@
+use crate::ValidationMethods;
+
+const HTTP_TOKEN_SYMBOLS: &str = "!#$%&'*+-.^_`|~";
+
+impl ValidationMethods {
+    pub fn validate_http_header_name(name: &str) -> bool {
+        is_http_token(name)
+    }
+
+    pub fn validate_http_header_value(value: &str) -> bool {
+        value
+            .chars()
+            .all(|c| c.is_ascii_graphic() || c == ' ' || c == '\t')
+    }
+}
+
+pub(crate) fn is_http_token(token: &str) -> bool {
+    !token.is_empty()
+        && token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || HTTP_TOKEN_SYMBOLS.contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_http_header_name() {
+        assert!(ValidationMethods::validate_http_header_name("Content-Type"));
+        assert!(ValidationMethods::validate_http_header_name("X-Request-ID"));
+        assert!(ValidationMethods::validate_http_header_name(
+            "x_custom.header~1"
+        ));
+        assert!(!ValidationMethods::validate_http_header_name(
+            "Content Type"
+        ));
+        assert!(!ValidationMethods::validate_http_header_name(
+            "Content-Type:"
+        ));
+        assert!(!ValidationMethods::validate_http_header_name("X-(Comment)"));
+        assert!(!ValidationMethods::validate_http_header_name(
+            "X-Header\r\n"
+        ));
+        assert!(!ValidationMethods::validate_http_header_name("Zażółć"));
+        assert!(!ValidationMethods::validate_http_header_name(""));
+    }
+
+    #[test]
+    fn test_validate_http_header_value() {
+        assert!(ValidationMethods::validate_http_header_value(
+            "text/html; charset=utf-8"
+        ));
+        assert!(ValidationMethods::validate_http_header_value("a\tb"));
+        assert!(ValidationMethods::validate_http_header_value(""));
+        assert!(!ValidationMethods::validate_http_header_value(
+            "value\r\nSet-Cookie: session=evil"
+        ));
+        assert!(!ValidationMethods::validate_http_header_value(
+            "value\nX-Injected: 1"
+        ));
+        assert!(!ValidationMethods::validate_http_header_value("value\r"));
+        assert!(!ValidationMethods::validate_http_header_value("null\0byte"));
+        assert!(!ValidationMethods::validate_http_header_value("café"));
+    }
+}
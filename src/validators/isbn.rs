@@ -0,0 +1,69 @@
+use super::checksum::gtin;
+use crate::ValidationMethods;
+use alloc::vec::Vec;
+
+impl ValidationMethods {
+    pub fn validate_isbn10(isbn: &str) -> bool {
+        let isbn: Vec<char> = isbn.chars().filter(|c| !matches!(c, '-' | ' ')).collect();
+        if isbn.len() != 10 {
+            return false;
+        }
+
+        let mut sum = 0;
+        for (index, c) in isbn.iter().enumerate() {
+            let value = match c.to_digit(10) {
+                Some(digit) => digit,
+                None if index == 9 && matches!(c, 'X' | 'x') => 10,
+                None => return false,
+            };
+            sum += value * (10 - index as u32);
+        }
+
+        sum.is_multiple_of(11)
+    }
+
+    pub fn validate_isbn13(isbn: &str) -> bool {
+        let digits: Vec<u32> = match isbn
+            .chars()
+            .filter(|c| !matches!(c, '-' | ' '))
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<u32>>>()
+        {
+            Some(digits) if digits.len() == 13 => digits,
+            _ => return false,
+        };
+
+        // ISBN-13 is an EAN-13 in the 978/979 "Bookland" prefix.
+        digits[..2] == [9, 7] && matches!(digits[2], 8 | 9) && gtin(&digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_isbn10() {
+        assert!(ValidationMethods::validate_isbn10("0306406152"));
+        assert!(ValidationMethods::validate_isbn10("0-306-40615-2"));
+        assert!(ValidationMethods::validate_isbn10("080442957X"));
+        assert!(ValidationMethods::validate_isbn10("0 8044 2957 x"));
+        assert!(!ValidationMethods::validate_isbn10("0306406153"));
+        assert!(!ValidationMethods::validate_isbn10("X306406152"));
+        assert!(!ValidationMethods::validate_isbn10("030640615"));
+        assert!(!ValidationMethods::validate_isbn10("9780306406157"));
+        assert!(!ValidationMethods::validate_isbn10(""));
+    }
+
+    #[test]
+    fn test_validate_isbn13() {
+        assert!(ValidationMethods::validate_isbn13("9780306406157"));
+        assert!(ValidationMethods::validate_isbn13("978-0-306-40615-7"));
+        assert!(ValidationMethods::validate_isbn13("978 3 16 148410 0"));
+        assert!(!ValidationMethods::validate_isbn13("9780306406158"));
+        assert!(!ValidationMethods::validate_isbn13("4006381333931"));
+        assert!(!ValidationMethods::validate_isbn13("978030640615"));
+        assert!(!ValidationMethods::validate_isbn13("978-0-306-40615-X"));
+        assert!(!ValidationMethods::validate_isbn13(""));
+    }
+}
@@ -0,0 +1,216 @@
+use crate::ValidationMethods;
+use core::iter::Peekable;
+use core::str::Chars;
+
+impl ValidationMethods {
+    /// Structurally validates a JSONPath expression.
+    ///
+    /// Checks the shape of `$`, `.name`, `..name`, `*` and `[...]` segments,
+    /// including quoted names, indices, slices, unions and `[?(...)]` filters
+    /// with balanced parentheses and quotes. Filter bodies are not parsed, so
+    /// `$[?(@.a ==)]` passes.
+    pub fn validate_jsonpath(path: &str) -> bool {
+        let mut chars = path.chars().peekable();
+        if chars.next() != Some('$') {
+            return false;
+        }
+
+        while let Some(c) = chars.next() {
+            let valid = match c {
+                '.' if chars.next_if_eq(&'.').is_some() => match chars.peek() {
+                    Some('[') => {
+                        chars.next();
+                        parse_subscript(&mut chars)
+                    }
+                    _ => parse_member(&mut chars),
+                },
+                '.' => parse_member(&mut chars),
+                '[' => parse_subscript(&mut chars),
+                _ => false,
+            };
+            if !valid {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn is_member_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+fn parse_member(chars: &mut Peekable<Chars>) -> bool {
+    if chars.next_if_eq(&'*').is_some() {
+        return true;
+    }
+    let mut len = 0;
+    while chars.next_if(|&c| is_member_char(c)).is_some() {
+        len += 1;
+    }
+    len > 0
+}
+
+// Parses the inside of `[...]` after the opening bracket, consuming the `]`.
+fn parse_subscript(chars: &mut Peekable<Chars>) -> bool {
+    if chars.next_if_eq(&'?').is_some() {
+        return chars.next() == Some('(') && parse_filter(chars) && chars.next() == Some(']');
+    }
+
+    loop {
+        skip_spaces(chars);
+        let valid = match chars.peek() {
+            Some('*') => {
+                chars.next();
+                true
+            }
+            Some(&quote @ ('\'' | '"')) => {
+                chars.next();
+                parse_quoted(chars, quote)
+            }
+            Some(_) => parse_slice_or_index(chars),
+            None => false,
+        };
+        skip_spaces(chars);
+        if !valid {
+            return false;
+        }
+        match chars.next() {
+            Some(']') => return true,
+            Some(',') => continue,
+            _ => return false,
+        }
+    }
+}
+
+// Accepts `1`, `-1`, `1:2`, `:2`, `::-1` and similar; at most two colons.
+fn parse_slice_or_index(chars: &mut Peekable<Chars>) -> bool {
+    let mut colons = 0;
+    let mut has_number = false;
+    loop {
+        match parse_integer(chars) {
+            Some(true) => has_number = true,
+            Some(false) => {}
+            None => return false,
+        }
+        match chars.peek() {
+            Some(':') if colons < 2 => {
+                chars.next();
+                colons += 1;
+            }
+            _ => break,
+        }
+    }
+    has_number || colons > 0
+}
+
+// Returns whether an integer was consumed, or `None` for a dangling `-`.
+fn parse_integer(chars: &mut Peekable<Chars>) -> Option<bool> {
+    let negative = chars.next_if_eq(&'-').is_some();
+    let mut digits = 0;
+    while chars.next_if(char::is_ascii_digit).is_some() {
+        digits += 1;
+    }
+    match (negative, digits) {
+        (true, 0) => None,
+        (_, digits) => Some(digits > 0),
+    }
+}
+
+fn parse_quoted(chars: &mut Peekable<Chars>, quote: char) -> bool {
+    while let Some(c) = chars.next() {
+        if c == quote {
+            return true;
+        }
+        if c == '\\' && chars.next().is_none() {
+            return false;
+        }
+    }
+    false
+}
+
+// Consumes a filter body up to and including its closing `)`.
+fn parse_filter(chars: &mut Peekable<Chars>) -> bool {
+    let mut depth = 1;
+    let mut is_empty = true;
+    while let Some(c) = chars.next() {
+        if matches!(c, '\'' | '"') && !parse_quoted(chars, c) {
+            return false;
+        }
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return !is_empty;
+                }
+            }
+            _ => {}
+        }
+        if !c.is_whitespace() {
+            is_empty = false;
+        }
+    }
+    false
+}
+
+fn skip_spaces(chars: &mut Peekable<Chars>) {
+    while chars.next_if_eq(&' ').is_some() {}
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_jsonpath() {
+        assert!(ValidationMethods::validate_jsonpath("$"));
+        assert!(ValidationMethods::validate_jsonpath(
+            "$.store.book[*].author"
+        ));
+        assert!(ValidationMethods::validate_jsonpath("$..price"));
+        assert!(ValidationMethods::validate_jsonpath("$..*"));
+        assert!(ValidationMethods::validate_jsonpath("$..[0]"));
+        assert!(ValidationMethods::validate_jsonpath("$.store.*"));
+        assert!(ValidationMethods::validate_jsonpath("$.book[0]"));
+        assert!(ValidationMethods::validate_jsonpath("$.book[-1]"));
+        assert!(ValidationMethods::validate_jsonpath("$.book[0,1]"));
+        assert!(ValidationMethods::validate_jsonpath("$.book[:2]"));
+        assert!(ValidationMethods::validate_jsonpath("$.book[1:10:2]"));
+        assert!(ValidationMethods::validate_jsonpath("$.book[::-1]"));
+        assert!(ValidationMethods::validate_jsonpath("$['store']['book']"));
+        assert!(ValidationMethods::validate_jsonpath("$[\"a]b\", 'c\\'d']"));
+        assert!(ValidationMethods::validate_jsonpath(
+            "$..book[?(@.price < 10)]"
+        ));
+        assert!(ValidationMethods::validate_jsonpath(
+            "$..book[?(@.author == 'J. (R.) Tolkien')]"
+        ));
+        assert!(ValidationMethods::validate_jsonpath(
+            "$..book[?((@.price > 1) && (@.isbn))]"
+        ));
+    }
+
+    #[test]
+    fn test_validate_jsonpath_rejects_malformed() {
+        assert!(!ValidationMethods::validate_jsonpath("$[invalid"));
+        assert!(!ValidationMethods::validate_jsonpath(""));
+        assert!(!ValidationMethods::validate_jsonpath("store.book"));
+        assert!(!ValidationMethods::validate_jsonpath("$."));
+        assert!(!ValidationMethods::validate_jsonpath("$..."));
+        assert!(!ValidationMethods::validate_jsonpath("$.a.."));
+        assert!(!ValidationMethods::validate_jsonpath("$.a b"));
+        assert!(!ValidationMethods::validate_jsonpath("$[]"));
+        assert!(!ValidationMethods::validate_jsonpath("$[0"));
+        assert!(!ValidationMethods::validate_jsonpath("$[0,]"));
+        assert!(!ValidationMethods::validate_jsonpath("$[-]"));
+        assert!(!ValidationMethods::validate_jsonpath("$[-:1]"));
+        assert!(!ValidationMethods::validate_jsonpath("$[1:2:3:4]"));
+        assert!(!ValidationMethods::validate_jsonpath("$['unclosed]"));
+        assert!(!ValidationMethods::validate_jsonpath("$[?(@.a]"));
+        assert!(!ValidationMethods::validate_jsonpath("$[?()]"));
+        assert!(!ValidationMethods::validate_jsonpath("$[?@.a]"));
+        assert!(!ValidationMethods::validate_jsonpath("$[?(@.a == ')')"));
+        assert!(!ValidationMethods::validate_jsonpath("$]"));
+    }
+}
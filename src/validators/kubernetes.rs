@@ -0,0 +1,147 @@
+use crate::ValidationMethods;
+
+const K8S_NAME_MAX_LENGTH: usize = 63;
+const K8S_PREFIX_MAX_LENGTH: usize = 253;
+// Helm appends suffixes to release names when naming resources, so it caps
+// names below the 63-character label limit.
+const HELM_NAME_MAX_LENGTH: usize = 53;
+
+impl ValidationMethods {
+    pub fn validate_k8s_label_value(value: &str) -> bool {
+        value.is_empty() || is_qualified_name_segment(value)
+    }
+
+    pub fn validate_k8s_label_key(key: &str) -> bool {
+        match key.split_once('/') {
+            Some((prefix, name)) => is_dns1123_subdomain(prefix) && is_qualified_name_segment(name),
+            None => is_qualified_name_segment(key),
+        }
+    }
+
+    pub fn validate_k8s_namespace(namespace: &str) -> bool {
+        is_dns1123_label(namespace)
+    }
+
+    pub fn validate_helm_chart_name(name: &str) -> bool {
+        is_helm_name(name)
+    }
+
+    pub fn validate_helm_release_name(name: &str) -> bool {
+        is_helm_name(name)
+    }
+}
+
+fn is_helm_name(name: &str) -> bool {
+    name.len() <= HELM_NAME_MAX_LENGTH && is_dns1123_label(name) && !name.contains("--")
+}
+
+fn is_qualified_name_segment(name: &str) -> bool {
+    (1..=K8S_NAME_MAX_LENGTH).contains(&name.len())
+        && name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && name.ends_with(|c: char| c.is_ascii_alphanumeric())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn is_dns1123_label(label: &str) -> bool {
+    (1..=K8S_NAME_MAX_LENGTH).contains(&label.len())
+        && label.starts_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && label.ends_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && label
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+fn is_dns1123_subdomain(subdomain: &str) -> bool {
+    subdomain.len() <= K8S_PREFIX_MAX_LENGTH && subdomain.split('.').all(is_dns1123_label)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_k8s_label_value() {
+        assert!(ValidationMethods::validate_k8s_label_value(""));
+        assert!(ValidationMethods::validate_k8s_label_value("production"));
+        assert!(ValidationMethods::validate_k8s_label_value("v1.2.3"));
+        assert!(ValidationMethods::validate_k8s_label_value("MyApp_2"));
+        assert!(ValidationMethods::validate_k8s_label_value(&"a".repeat(63)));
+        assert!(!ValidationMethods::validate_k8s_label_value(
+            &"a".repeat(64)
+        ));
+        assert!(!ValidationMethods::validate_k8s_label_value("-frontend"));
+        assert!(!ValidationMethods::validate_k8s_label_value("frontend."));
+        assert!(!ValidationMethods::validate_k8s_label_value("front end"));
+        assert!(!ValidationMethods::validate_k8s_label_value(
+            "tier/frontend"
+        ));
+    }
+
+    #[test]
+    fn test_validate_k8s_label_key() {
+        assert!(ValidationMethods::validate_k8s_label_key("environment"));
+        assert!(ValidationMethods::validate_k8s_label_key(
+            "app.kubernetes.io/name"
+        ));
+        assert!(ValidationMethods::validate_k8s_label_key(
+            "kubernetes.io/arch"
+        ));
+        assert!(ValidationMethods::validate_k8s_label_key(
+            "example.com/Release_Track"
+        ));
+        assert!(!ValidationMethods::validate_k8s_label_key(""));
+        assert!(!ValidationMethods::validate_k8s_label_key("/name"));
+        assert!(!ValidationMethods::validate_k8s_label_key("example.com/"));
+        assert!(!ValidationMethods::validate_k8s_label_key(
+            "Example.com/name"
+        ));
+        assert!(!ValidationMethods::validate_k8s_label_key(
+            "example_com/name"
+        ));
+        assert!(!ValidationMethods::validate_k8s_label_key("a/b/c"));
+        assert!(!ValidationMethods::validate_k8s_label_key(&"a".repeat(64)));
+    }
+
+    #[test]
+    fn test_validate_k8s_namespace() {
+        assert!(ValidationMethods::validate_k8s_namespace("default"));
+        assert!(ValidationMethods::validate_k8s_namespace("kube-system"));
+        assert!(ValidationMethods::validate_k8s_namespace("team-42"));
+        assert!(!ValidationMethods::validate_k8s_namespace("Default"));
+        assert!(!ValidationMethods::validate_k8s_namespace("my_namespace"));
+        assert!(!ValidationMethods::validate_k8s_namespace("-system"));
+        assert!(!ValidationMethods::validate_k8s_namespace("my.namespace"));
+        assert!(!ValidationMethods::validate_k8s_namespace(""));
+    }
+
+    #[test]
+    fn test_validate_helm_chart_name() {
+        assert!(ValidationMethods::validate_helm_chart_name("my-chart"));
+        assert!(ValidationMethods::validate_helm_chart_name("a"));
+        assert!(ValidationMethods::validate_helm_chart_name(
+            "nginx-ingress2"
+        ));
+        assert!(!ValidationMethods::validate_helm_chart_name("My-Chart"));
+        assert!(!ValidationMethods::validate_helm_chart_name("chart-"));
+        assert!(!ValidationMethods::validate_helm_chart_name("-chart"));
+        assert!(!ValidationMethods::validate_helm_chart_name("my--chart"));
+        assert!(!ValidationMethods::validate_helm_chart_name("my_chart"));
+        assert!(!ValidationMethods::validate_helm_chart_name(""));
+
+        let max_length = "a".repeat(53);
+        assert!(ValidationMethods::validate_helm_chart_name(&max_length));
+        let too_long = "a".repeat(54);
+        assert!(!ValidationMethods::validate_helm_chart_name(&too_long));
+    }
+
+    #[test]
+    fn test_validate_helm_release_name() {
+        assert!(ValidationMethods::validate_helm_release_name("prod-api"));
+        assert!(ValidationMethods::validate_helm_release_name("a"));
+        assert!(!ValidationMethods::validate_helm_release_name("Prod"));
+        assert!(!ValidationMethods::validate_helm_release_name("release-"));
+        assert!(!ValidationMethods::validate_helm_release_name("prod.api"));
+    }
+}
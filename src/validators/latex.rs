@@ -0,0 +1,71 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_latex_command(command: &str) -> bool {
+        let name = match command.strip_prefix('\\') {
+            Some(name) => name,
+            None => return false,
+        };
+        let mut chars = name.chars();
+
+        // Control words are all letters; control symbols are one non-letter.
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => !c.is_whitespace() || c == ' ',
+            (Some(_), Some(_)) => name.chars().all(|c| c.is_ascii_alphabetic()),
+            _ => false,
+        }
+    }
+
+    pub fn validate_latex_environment_name(name: &str) -> bool {
+        let name = name.strip_suffix('*').unwrap_or(name);
+        let mut chars = name.chars();
+        chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_latex_command() {
+        assert!(ValidationMethods::validate_latex_command("\\textbf"));
+        assert!(ValidationMethods::validate_latex_command("\\section"));
+        assert!(ValidationMethods::validate_latex_command("\\,"));
+        assert!(ValidationMethods::validate_latex_command("\\\\"));
+        assert!(ValidationMethods::validate_latex_command("\\%"));
+        assert!(ValidationMethods::validate_latex_command("\\ "));
+        assert!(!ValidationMethods::validate_latex_command("\\"));
+        assert!(ValidationMethods::validate_latex_command("\\a"));
+        assert!(!ValidationMethods::validate_latex_command("\\12"));
+        assert!(!ValidationMethods::validate_latex_command("\\text1"));
+        assert!(!ValidationMethods::validate_latex_command("\\,,"));
+        assert!(!ValidationMethods::validate_latex_command("\\\n"));
+        assert!(!ValidationMethods::validate_latex_command("textbf"));
+        assert!(!ValidationMethods::validate_latex_command(""));
+    }
+
+    #[test]
+    fn test_validate_latex_environment_name() {
+        assert!(ValidationMethods::validate_latex_environment_name("align*"));
+        assert!(ValidationMethods::validate_latex_environment_name(
+            "document"
+        ));
+        assert!(ValidationMethods::validate_latex_environment_name("h2"));
+        assert!(!ValidationMethods::validate_latex_environment_name(
+            "align**"
+        ));
+        assert!(!ValidationMethods::validate_latex_environment_name(
+            "al*ign"
+        ));
+        assert!(!ValidationMethods::validate_latex_environment_name(
+            "2column"
+        ));
+        assert!(!ValidationMethods::validate_latex_environment_name(
+            "my-env"
+        ));
+        assert!(!ValidationMethods::validate_latex_environment_name("*"));
+        assert!(!ValidationMethods::validate_latex_environment_name(""));
+    }
+}
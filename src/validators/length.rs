@@ -0,0 +1,30 @@
+pub fn min_length_validator(min: usize) -> impl Fn(&str) -> bool {
+    move |input| input.chars().count() >= min
+}
+
+pub fn max_length_validator(max: usize) -> impl Fn(&str) -> bool {
+    move |input| input.chars().count() <= max
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_length_validator() {
+        let validator = min_length_validator(3);
+        assert!(validator("abc"));
+        assert!(validator("żółw"));
+        assert!(!validator("ab"));
+        assert!(!validator(""));
+    }
+
+    #[test]
+    fn test_max_length_validator() {
+        let validator = max_length_validator(3);
+        assert!(validator("abc"));
+        assert!(validator("żół"));
+        assert!(validator(""));
+        assert!(!validator("abcd"));
+    }
+}
@@ -0,0 +1,46 @@
+use core::cmp::Ordering;
+
+// Binary search over the lines of a byte-sorted, newline-separated list
+// without building an index. Header comment lines sort before every entry
+// that starts with a letter or digit, so they are harmless.
+pub(crate) fn sorted_lines_contain(sorted: &str, needle: &str) -> bool {
+    let bytes = sorted.as_bytes();
+    let (mut low, mut high) = (0, bytes.len());
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let start = bytes[..mid]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |index| index + 1);
+        let end = bytes[mid..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(bytes.len(), |index| mid + index);
+
+        match sorted[start..end].cmp(needle) {
+            Ordering::Equal => return true,
+            Ordering::Less => low = end + 1,
+            Ordering::Greater => high = start,
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_lines_contain() {
+        let list = "# header\n# more header\nalpha.com\nbeta.com\ngamma.com\n";
+        assert!(sorted_lines_contain(list, "alpha.com"));
+        assert!(sorted_lines_contain(list, "beta.com"));
+        assert!(sorted_lines_contain(list, "gamma.com"));
+        assert!(!sorted_lines_contain(list, "beta"));
+        assert!(!sorted_lines_contain(list, "delta.com"));
+        assert!(!sorted_lines_contain(list, "zeta.com"));
+        assert!(!sorted_lines_contain("", "alpha.com"));
+        assert!(sorted_lines_contain("only", "only"));
+    }
+}
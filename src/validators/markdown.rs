@@ -0,0 +1,176 @@
+use crate::ValidationMethods;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub struct MarkdownValidator {
+    pub max_length: usize,
+    pub allow_html: bool,
+}
+
+impl MarkdownValidator {
+    pub fn validate(&self, input: &str) -> bool {
+        if input.chars().count() > self.max_length {
+            return false;
+        }
+
+        let mut fence: Option<&str> = None;
+        let mut text = String::new();
+
+        for line in input.lines() {
+            let trimmed = line.trim_start();
+            let marker = ["```", "~~~"]
+                .into_iter()
+                .find(|marker| trimmed.starts_with(marker));
+
+            match (fence, marker) {
+                (None, Some(marker)) => fence = Some(marker),
+                (Some(open), Some(close)) if open == close => fence = None,
+                (Some(_), _) => {}
+                (None, None) => {
+                    text.push_str(line);
+                    text.push('\n');
+                }
+            }
+        }
+
+        fence.is_none() && inline_structure_is_valid(&text, self.allow_html)
+    }
+}
+
+impl ValidationMethods {
+    pub fn validate_markdown(input: &str) -> bool {
+        MarkdownValidator {
+            max_length: usize::MAX,
+            allow_html: true,
+        }
+        .validate(input)
+    }
+}
+
+fn inline_structure_is_valid(text: &str, allow_html: bool) -> bool {
+    let chars: Vec<char> = text.chars().collect();
+    let mut bracket_depth = 0usize;
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            '`' => {
+                let run = run_length(&chars[i..], '`');
+                match find_closing_backticks(&chars, i + run, run) {
+                    Some(end) => i = end,
+                    None => return false,
+                }
+                continue;
+            }
+            '[' => bracket_depth += 1,
+            ']' => {
+                if bracket_depth == 0 {
+                    return false;
+                }
+                bracket_depth -= 1;
+
+                if chars.get(i + 1) == Some(&'(') {
+                    match find_closing_paren(&chars, i + 2) {
+                        Some(end) => i = end,
+                        None => return false,
+                    }
+                }
+            }
+            '<' if !allow_html && is_html_tag(&chars[i + 1..]) => return false,
+            _ => {}
+        }
+        i += 1;
+    }
+
+    bracket_depth == 0
+}
+
+fn run_length(chars: &[char], target: char) -> usize {
+    chars.iter().take_while(|&&c| c == target).count()
+}
+
+fn find_closing_backticks(chars: &[char], start: usize, run: usize) -> Option<usize> {
+    let mut i = start;
+
+    while i < chars.len() {
+        if chars[i] == '`' {
+            let length = run_length(&chars[i..], '`');
+            if length == run {
+                return Some(i + length);
+            }
+            i += length;
+        } else {
+            i += 1;
+        }
+    }
+
+    None
+}
+
+fn find_closing_paren(chars: &[char], start: usize) -> Option<usize> {
+    let mut depth = 0usize;
+
+    for (offset, &c) in chars[start..].iter().enumerate() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(start + offset),
+            ')' => depth -= 1,
+            '\n' => return None,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn is_html_tag(rest: &[char]) -> bool {
+    let rest = rest.strip_prefix(&['/']).unwrap_or(rest);
+    let is_autolink = rest
+        .iter()
+        .take_while(|&&c| c != '>' && !c.is_whitespace())
+        .any(|&c| c == ':' || c == '@');
+
+    rest.first().is_some_and(|c| c.is_ascii_alphabetic()) && !is_autolink
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = "# Title\n\nSome `inline code` and a [link](https://example.com/a_(b)).\n\n```rust\nlet unbalanced = [\"`\";\n```\n\n- item with ``a ` tick``\n";
+
+    #[test]
+    fn test_validate_markdown() {
+        assert!(ValidationMethods::validate_markdown(DOCUMENT));
+        assert!(ValidationMethods::validate_markdown("plain text"));
+        assert!(ValidationMethods::validate_markdown("escaped \\[ bracket"));
+        assert!(!ValidationMethods::validate_markdown("```\nlet x = 1;\n"));
+        assert!(!ValidationMethods::validate_markdown("unclosed `code span"));
+        assert!(!ValidationMethods::validate_markdown(
+            "[broken link(https://example.com)"
+        ));
+        assert!(!ValidationMethods::validate_markdown(
+            "[text](https://example.com"
+        ));
+        assert!(!ValidationMethods::validate_markdown("stray ] bracket"));
+    }
+
+    #[test]
+    fn test_markdown_validator() {
+        let strict = MarkdownValidator {
+            max_length: 40,
+            allow_html: false,
+        };
+        assert!(strict.validate("**bold** and <https://example.com>"));
+        assert!(strict.validate("`<b>` inside code is fine"));
+        assert!(!strict.validate("<b>bold</b>"));
+        assert!(!strict.validate(&"a".repeat(41)));
+
+        let lenient = MarkdownValidator {
+            max_length: 40,
+            allow_html: true,
+        };
+        assert!(lenient.validate("<b>bold</b>"));
+    }
+}
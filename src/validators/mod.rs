@@ -0,0 +1,96 @@
+mod app_id;
+mod aws;
+mod azure;
+mod banking;
+mod barcode;
+mod business;
+mod checksum;
+mod colors;
+mod comparison;
+mod country;
+mod cron;
+mod csp;
+mod cve;
+mod data_uri;
+mod date;
+mod docker;
+mod email;
+mod encoding;
+mod env;
+mod file;
+mod gcp;
+mod git;
+mod github;
+mod gitlab;
+mod graphql;
+mod homoglyph;
+mod http;
+mod isbn;
+mod jsonpath;
+mod kubernetes;
+mod latex;
+mod length;
+mod lookup;
+mod markdown;
+mod national_id;
+mod network;
+mod normalization;
+mod number_format;
+mod numeric;
+mod openapi;
+mod optional;
+mod package;
+mod pangram;
+mod path;
+#[cfg(feature = "regex")]
+mod pattern;
+mod payment;
+mod pem;
+mod phone;
+mod profanity;
+mod prometheus;
+mod protobuf;
+mod redos;
+mod repetition;
+mod securities;
+mod sql;
+mod terraform;
+mod tld;
+mod toml;
+mod totp;
+mod us_state;
+mod uuid;
+mod version;
+mod windows_registry;
+mod wolfram;
+mod x509;
+mod xml;
+mod xss;
+mod yaml;
+
+pub(crate) use banking::{is_fps_reference_char, FPS_REFERENCE_MAX_LENGTH};
+pub(crate) use checksum::gtin_check_digit;
+#[cfg(feature = "std")]
+pub(crate) use colors::parse_rgb;
+pub use comparison::{
+    changed_from_case_sensitive_validator, changed_from_validator, placeholder_validator,
+};
+pub use email::DisposableEmailValidator;
+pub use file::extension_allowlist_validator;
+pub use homoglyph::HomoglyphDetector;
+pub(crate) use http::is_http_token;
+pub use length::{max_length_validator, min_length_validator};
+pub use markdown::MarkdownValidator;
+pub use number_format::NumberFormatValidator;
+pub use openapi::OpenApiValidator;
+pub use package::normalize_pypi_name;
+#[cfg(feature = "regex")]
+pub use pattern::RegexValidator;
+pub use phone::PhoneValidator;
+pub use profanity::ProfanityValidator;
+pub use redos::{ReDoSSafeValidator, ReDoSWarning};
+pub use repetition::max_consecutive_validator;
+pub use securities::sedol_to_isin;
+pub use totp::TotpValidator;
+pub use us_state::us_state_full_name;
+pub use xss::{XssMode, XssValidator};
@@ -0,0 +1,194 @@
+use super::checksum::luhn;
+use crate::ValidationMethods;
+use alloc::vec::Vec;
+
+impl ValidationMethods {
+    /// Validates a 10-digit UK NHS number, ignoring spaces.
+    ///
+    /// Uses the Modulus 11 check from the NHS Data Model and Dictionary
+    /// (<https://www.datadictionary.nhs.uk/attributes/nhs_number.html>):
+    /// digits 1–9 are weighted 10 down to 2 and summed, the check digit is
+    /// `11 - (sum % 11)` with 11 mapped to 0, and a result of 10 is never valid.
+    pub fn validate_nhs_number(number: &str) -> bool {
+        let digits: Vec<u32> = match number
+            .chars()
+            .filter(|c| *c != ' ')
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<u32>>>()
+        {
+            Some(digits) if digits.len() == 10 => digits,
+            _ => return false,
+        };
+
+        let sum: u32 = digits[..9]
+            .iter()
+            .zip((2..=10).rev())
+            .map(|(digit, weight)| digit * weight)
+            .sum();
+        let check = match 11 - sum % 11 {
+            11 => 0,
+            check => check,
+        };
+
+        check != 10 && check == digits[9]
+    }
+
+    pub fn validate_bsn(number: &str) -> bool {
+        if !matches!(number.len(), 8 | 9) {
+            return false;
+        }
+        let digits: Vec<i32> = match number
+            .chars()
+            .map(|c| c.to_digit(10).map(|digit| digit as i32))
+            .collect::<Option<Vec<i32>>>()
+        {
+            Some(digits) => digits,
+            None => return false,
+        };
+
+        // An 8-digit BSN is a 9-digit one with its leading zero dropped.
+        let weights = [9, 8, 7, 6, 5, 4, 3, 2, -1];
+        let sum: i32 = digits
+            .iter()
+            .rev()
+            .zip(weights.iter().rev())
+            .map(|(digit, weight)| digit * weight)
+            .sum();
+
+        sum != 0 && sum % 11 == 0
+    }
+
+    pub fn validate_canadian_sin(number: &str) -> bool {
+        let grouped =
+            number.len() == 11 && number.as_bytes()[3] == b'-' && number.as_bytes()[7] == b'-';
+        if number.len() != 9 && !grouped {
+            return false;
+        }
+        let digits: Vec<u32> = match number
+            .chars()
+            .filter(|c| !grouped || *c != '-')
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<u32>>>()
+        {
+            Some(digits) if digits.len() == 9 => digits,
+            _ => return false,
+        };
+
+        // SINs starting with 0 or 8 are not issued to individuals.
+        !matches!(digits[0], 0 | 8) && luhn(&digits)
+    }
+
+    pub fn validate_tfn(number: &str) -> bool {
+        Self::validate_tfn_with_all_nines(number, false)
+    }
+
+    /// Validates an Australian Tax File Number, ignoring spaces.
+    ///
+    /// When `accept_all_nines` is set the non-resident placeholder
+    /// `999 999 999` is accepted even though it fails the checksum.
+    pub fn validate_tfn_with_all_nines(number: &str, accept_all_nines: bool) -> bool {
+        let digits: Vec<u32> = match number
+            .chars()
+            .filter(|c| *c != ' ')
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<u32>>>()
+        {
+            Some(digits) if matches!(digits.len(), 8 | 9) => digits,
+            _ => return false,
+        };
+        if accept_all_nines && digits.len() == 9 && digits.iter().all(|&digit| digit == 9) {
+            return true;
+        }
+
+        let weights = [1, 4, 3, 7, 5, 8, 6, 9, 10];
+        let sum: u32 = digits
+            .iter()
+            .zip(weights)
+            .map(|(digit, weight)| digit * weight)
+            .sum();
+
+        sum.is_multiple_of(11)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_nhs_number() {
+        assert!(ValidationMethods::validate_nhs_number("9434765919"));
+        assert!(ValidationMethods::validate_nhs_number("943 476 5919"));
+        assert!(ValidationMethods::validate_nhs_number("4010232137"));
+        assert!(!ValidationMethods::validate_nhs_number("9434765918"));
+        assert!(!ValidationMethods::validate_nhs_number("1000000010"));
+        assert!(!ValidationMethods::validate_nhs_number("943476591"));
+        assert!(!ValidationMethods::validate_nhs_number("94347659190"));
+        assert!(!ValidationMethods::validate_nhs_number("943-476-5919"));
+        assert!(!ValidationMethods::validate_nhs_number(""));
+    }
+
+    #[test]
+    fn test_validate_bsn() {
+        assert!(ValidationMethods::validate_bsn("111222333"));
+        assert!(ValidationMethods::validate_bsn("123456782"));
+        assert!(ValidationMethods::validate_bsn("999999990"));
+        assert!(ValidationMethods::validate_bsn("12345672"));
+        assert!(!ValidationMethods::validate_bsn("123456789"));
+        assert!(!ValidationMethods::validate_bsn("111222334"));
+        assert!(!ValidationMethods::validate_bsn("000000000"));
+        assert!(!ValidationMethods::validate_bsn("1234567"));
+        assert!(!ValidationMethods::validate_bsn("1234567820"));
+        assert!(!ValidationMethods::validate_bsn("12345678a"));
+    }
+
+    #[test]
+    fn test_validate_canadian_sin() {
+        assert!(ValidationMethods::validate_canadian_sin("130692544"));
+        assert!(ValidationMethods::validate_canadian_sin("130-692-544"));
+        assert!(ValidationMethods::validate_canadian_sin("193456787"));
+        assert!(!ValidationMethods::validate_canadian_sin("130692545"));
+        assert!(!ValidationMethods::validate_canadian_sin("046454286"));
+        assert!(!ValidationMethods::validate_canadian_sin("800000002"));
+        assert!(!ValidationMethods::validate_canadian_sin("13069254"));
+        assert!(!ValidationMethods::validate_canadian_sin("1306925440"));
+        assert!(!ValidationMethods::validate_canadian_sin("13-0692-544"));
+        assert!(!ValidationMethods::validate_canadian_sin("130 692 544"));
+        assert!(!ValidationMethods::validate_canadian_sin("13069254a"));
+    }
+
+    #[test]
+    fn test_validate_tfn() {
+        assert!(ValidationMethods::validate_tfn("123456782"));
+        assert!(ValidationMethods::validate_tfn("876 543 210"));
+        assert!(ValidationMethods::validate_tfn("459599230"));
+        assert!(ValidationMethods::validate_tfn("12345679"));
+        assert!(ValidationMethods::validate_tfn("8765 4321"));
+        assert!(!ValidationMethods::validate_tfn("123456789"));
+        assert!(!ValidationMethods::validate_tfn("12345677"));
+        assert!(!ValidationMethods::validate_tfn("999999999"));
+        assert!(!ValidationMethods::validate_tfn("1234567"));
+        assert!(!ValidationMethods::validate_tfn("1234567820"));
+        assert!(!ValidationMethods::validate_tfn("123-456-782"));
+        assert!(!ValidationMethods::validate_tfn(""));
+    }
+
+    #[test]
+    fn test_validate_tfn_with_all_nines() {
+        assert!(ValidationMethods::validate_tfn_with_all_nines(
+            "999 999 999",
+            true
+        ));
+        assert!(!ValidationMethods::validate_tfn_with_all_nines(
+            "999 999 999",
+            false
+        ));
+        assert!(!ValidationMethods::validate_tfn_with_all_nines(
+            "99999999", true
+        ));
+        assert!(ValidationMethods::validate_tfn_with_all_nines(
+            "123456782",
+            true
+        ));
+    }
+}
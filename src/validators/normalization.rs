@@ -0,0 +1,22 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_nfc_normalized(input: &str) -> bool {
+        unicode_normalization::is_nfc(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_nfc_normalized() {
+        assert!(ValidationMethods::validate_nfc_normalized("caf\u{e9}"));
+        assert!(ValidationMethods::validate_nfc_normalized("plain ascii"));
+        assert!(!ValidationMethods::validate_nfc_normalized("cafe\u{301}"));
+        assert!(!ValidationMethods::validate_nfc_normalized(
+            "A\u{30a}ngstr\u{f6}m"
+        ));
+    }
+}
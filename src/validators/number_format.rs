@@ -0,0 +1,107 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub struct NumberFormatValidator {
+    decimal_separator: char,
+    thousands_separator: char,
+}
+
+impl NumberFormatValidator {
+    /// Returns `None` when both separators are the same character, since
+    /// `1.234` could then be read either way.
+    pub fn new(decimal_separator: char, thousands_separator: char) -> Option<Self> {
+        (decimal_separator != thousands_separator).then_some(Self {
+            decimal_separator,
+            thousands_separator,
+        })
+    }
+
+    pub fn validate(&self, input: &str) -> bool {
+        self.normalize(input).is_some()
+    }
+
+    pub fn to_f64(&self, input: &str) -> Option<f64> {
+        self.normalize(input)?.parse().ok()
+    }
+
+    fn normalize(&self, input: &str) -> Option<String> {
+        let (sign, unsigned) = match input.strip_prefix(['-', '+']) {
+            Some(rest) => (&input[..1], rest),
+            None => ("", input),
+        };
+        let (integer, fraction) = match unsigned.split_once(self.decimal_separator) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+
+        let groups: Vec<&str> = integer.split(self.thousands_separator).collect();
+        let grouping_is_valid = match groups.as_slice() {
+            [single] => is_digits(single),
+            [first, rest @ ..] => {
+                is_digits(first)
+                    && first.len() <= 3
+                    && rest
+                        .iter()
+                        .all(|group| group.len() == 3 && is_digits(group))
+            }
+            [] => false,
+        };
+
+        if !grouping_is_valid || fraction.is_some_and(|fraction| !is_digits(fraction)) {
+            return None;
+        }
+
+        let mut normalized = format!("{sign}{}", groups.concat());
+        if let Some(fraction) = fraction {
+            normalized.push('.');
+            normalized.push_str(fraction);
+        }
+        Some(normalized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_format_validator() {
+        let us = NumberFormatValidator::new('.', ',').unwrap();
+        assert!(us.validate("1,234,567.89"));
+        assert!(us.validate("1234567.89"));
+        assert!(us.validate("-12,345"));
+        assert!(us.validate("999"));
+        assert!(!us.validate("1,23,456"));
+        assert!(!us.validate("1,2345"));
+        assert!(!us.validate(",123"));
+        assert!(!us.validate("1,234.5.6"));
+        assert!(!us.validate("1,234."));
+        assert!(!us.validate("1.234,56"));
+        assert!(!us.validate("12a"));
+        assert!(!us.validate(""));
+
+        let de = NumberFormatValidator::new(',', '.').unwrap();
+        assert!(de.validate("1.234.567,89"));
+        assert!(!de.validate("1,234,567.89"));
+    }
+
+    #[test]
+    fn test_number_format_validator_same_separators() {
+        assert!(NumberFormatValidator::new('.', '.').is_none());
+        assert!(NumberFormatValidator::new(',', ',').is_none());
+    }
+
+    #[test]
+    fn test_number_format_validator_to_f64() {
+        let us = NumberFormatValidator::new('.', ',').unwrap();
+        assert_eq!(us.to_f64("1,234,567.89"), Some(1_234_567.89));
+        assert_eq!(us.to_f64("-1,000"), Some(-1000.0));
+        assert_eq!(us.to_f64("1,2345"), None);
+
+        let fr = NumberFormatValidator::new(',', ' ').unwrap();
+        assert_eq!(fr.to_f64("1 234,5"), Some(1234.5));
+    }
+}
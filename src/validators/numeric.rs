@@ -0,0 +1,48 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_proportional_fraction(value: &str) -> bool {
+        parse_finite(value).is_some_and(|fraction| (0.0..=1.0).contains(&fraction))
+    }
+
+    pub fn validate_open_fraction(value: &str) -> bool {
+        parse_finite(value).is_some_and(|fraction| fraction > 0.0 && fraction < 1.0)
+    }
+}
+
+fn parse_finite(value: &str) -> Option<f64> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_proportional_fraction() {
+        assert!(ValidationMethods::validate_proportional_fraction("0.0"));
+        assert!(ValidationMethods::validate_proportional_fraction("1.0"));
+        assert!(ValidationMethods::validate_proportional_fraction("0.5"));
+        assert!(ValidationMethods::validate_proportional_fraction("1"));
+        assert!(!ValidationMethods::validate_proportional_fraction(
+            "1.0000001"
+        ));
+        assert!(!ValidationMethods::validate_proportional_fraction("-0.1"));
+        assert!(!ValidationMethods::validate_proportional_fraction("nan"));
+        assert!(!ValidationMethods::validate_proportional_fraction("inf"));
+        assert!(!ValidationMethods::validate_proportional_fraction("0.5abc"));
+        assert!(!ValidationMethods::validate_proportional_fraction(""));
+    }
+
+    #[test]
+    fn test_validate_open_fraction() {
+        assert!(ValidationMethods::validate_open_fraction("0.5"));
+        assert!(ValidationMethods::validate_open_fraction("0.0001"));
+        assert!(!ValidationMethods::validate_open_fraction("0.0"));
+        assert!(!ValidationMethods::validate_open_fraction("1.0"));
+        assert!(!ValidationMethods::validate_open_fraction("nan"));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::ValidationMethods;
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+
+impl ValidationMethods {
+    pub fn validate_openapi_operation_id(id: &str) -> bool {
+        let mut chars = id.chars();
+        chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    pub fn validate_openapi_path_template(path: &str) -> bool {
+        let mut rest = match path.strip_prefix('/') {
+            Some(rest) if !path.contains("//") => rest,
+            _ => return false,
+        };
+        let mut parameters = BTreeSet::new();
+
+        while !rest.is_empty() {
+            match rest.find(['{', '}']) {
+                Some(start) if rest[start..].starts_with('{') => {
+                    let end = match rest[start..].find('}') {
+                        Some(end) => start + end,
+                        None => return false,
+                    };
+                    let name = &rest[start + 1..end];
+                    if !is_path_literal(&rest[..start])
+                        || !is_parameter_name(name)
+                        || !parameters.insert(name)
+                    {
+                        return false;
+                    }
+                    rest = &rest[end + 1..];
+                }
+                Some(_) => return false,
+                None => return is_path_literal(rest),
+            }
+        }
+        true
+    }
+}
+
+pub struct OpenApiValidator {
+    operation_ids: BTreeSet<String>,
+}
+
+impl OpenApiValidator {
+    pub fn new() -> Self {
+        Self {
+            operation_ids: BTreeSet::new(),
+        }
+    }
+
+    // Accepts an `operationId` only the first time it is seen.
+    pub fn validate_operation_id(&mut self, id: &str) -> bool {
+        ValidationMethods::validate_openapi_operation_id(id) && self.operation_ids.insert(id.into())
+    }
+}
+
+impl Default for OpenApiValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_parameter_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// Path characters allowed by RFC 3986 `pchar`, plus `/` between segments.
+fn is_path_literal(literal: &str) -> bool {
+    literal
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "-._~!$&'()*+,;=:@%/".contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_openapi_operation_id() {
+        assert!(ValidationMethods::validate_openapi_operation_id("listPets"));
+        assert!(ValidationMethods::validate_openapi_operation_id(
+            "get_pet_by_id"
+        ));
+        assert!(ValidationMethods::validate_openapi_operation_id("v2Update"));
+        assert!(!ValidationMethods::validate_openapi_operation_id("2update"));
+        assert!(!ValidationMethods::validate_openapi_operation_id(
+            "_private"
+        ));
+        assert!(!ValidationMethods::validate_openapi_operation_id(
+            "list-pets"
+        ));
+        assert!(!ValidationMethods::validate_openapi_operation_id(
+            "list pets"
+        ));
+        assert!(!ValidationMethods::validate_openapi_operation_id(""));
+    }
+
+    #[test]
+    fn test_openapi_validator_tracks_uniqueness() {
+        let mut validator = OpenApiValidator::new();
+        assert!(validator.validate_operation_id("listPets"));
+        assert!(validator.validate_operation_id("createPet"));
+        assert!(!validator.validate_operation_id("listPets"));
+        assert!(!validator.validate_operation_id("bad-id"));
+        assert!(validator.validate_operation_id("listpets"));
+    }
+
+    #[test]
+    fn test_validate_openapi_path_template() {
+        assert!(ValidationMethods::validate_openapi_path_template("/"));
+        assert!(ValidationMethods::validate_openapi_path_template("/pets"));
+        assert!(ValidationMethods::validate_openapi_path_template(
+            "/pets/{petId}"
+        ));
+        assert!(ValidationMethods::validate_openapi_path_template(
+            "/users/{user_id}/orders/{orderId}/"
+        ));
+        assert!(ValidationMethods::validate_openapi_path_template(
+            "/reports/{id}.{format}"
+        ));
+        assert!(!ValidationMethods::validate_openapi_path_template(
+            "pets/{petId}"
+        ));
+        assert!(!ValidationMethods::validate_openapi_path_template(
+            "/pets/{petId"
+        ));
+        assert!(!ValidationMethods::validate_openapi_path_template(
+            "/pets/petId}"
+        ));
+        assert!(!ValidationMethods::validate_openapi_path_template(
+            "/pets/{}"
+        ));
+        assert!(!ValidationMethods::validate_openapi_path_template(
+            "/pets/{pet-id}"
+        ));
+        assert!(!ValidationMethods::validate_openapi_path_template(
+            "/pets/{{petId}}"
+        ));
+        assert!(!ValidationMethods::validate_openapi_path_template(
+            "/a/{id}/b/{id}"
+        ));
+        assert!(!ValidationMethods::validate_openapi_path_template(
+            "/pets//{petId}"
+        ));
+        assert!(!ValidationMethods::validate_openapi_path_template(
+            "/pets?limit=10"
+        ));
+        assert!(!ValidationMethods::validate_openapi_path_template(
+            "/my pets"
+        ));
+        assert!(!ValidationMethods::validate_openapi_path_template(""));
+    }
+}
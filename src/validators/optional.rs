@@ -0,0 +1,36 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_optional_format(validator: impl Fn(&str) -> bool) -> impl Fn(&str) -> bool {
+        move |value| value.is_empty() || validator(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{html5_validators, ValidationMethods};
+
+    #[test]
+    fn test_validate_optional_format() {
+        let validator = ValidationMethods::validate_optional_format(html5_validators::email);
+        assert!(validator(""));
+        assert!(validator("test@example.com"));
+        assert!(!validator("notanemail"));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_validate_optional_format_in_validator() {
+        use crate::{max_length_validator, Validator};
+
+        let validator = Validator::new(vec![])
+            .with_validation(ValidationMethods::validate_optional_format(
+                ValidationMethods::validate_email,
+            ))
+            .with_validation(max_length_validator(20));
+        assert!(validator.validate(""));
+        assert!(validator.validate("test@example.com"));
+        assert!(!validator.validate("notanemail"));
+        assert!(!validator.validate("much.too.long@example.com"));
+    }
+}
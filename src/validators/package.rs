@@ -0,0 +1,375 @@
+use crate::ValidationMethods;
+use alloc::string::String;
+
+const NPM_NAME_MAX_LENGTH: usize = 214;
+
+// Node.js core modules (`require('module').builtinModules`) plus the two
+// names the npm registry blocks outright, sorted for binary search.
+const NPM_RESERVED_NAMES: [&str; 44] = [
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "favicon.ico",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "node_modules",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+const CRATE_NAME_MAX_LENGTH: usize = 64;
+const CONDA_NAME_MAX_LENGTH: usize = 100;
+
+// Strict, reserved and edition-reserved Rust keywords, sorted for binary search.
+const RUST_KEYWORDS: [&str; 52] = [
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+// Widely depended-on crates, used to flag case-only lookalikes.
+const WELL_KNOWN_CRATES: [&str; 32] = [
+    "anyhow",
+    "base64",
+    "bitflags",
+    "bytes",
+    "cfg-if",
+    "chrono",
+    "clap",
+    "futures",
+    "hashbrown",
+    "hyper",
+    "itertools",
+    "lazy_static",
+    "libc",
+    "log",
+    "memchr",
+    "once_cell",
+    "parking_lot",
+    "proc-macro2",
+    "quote",
+    "rand",
+    "regex",
+    "reqwest",
+    "serde",
+    "serde_json",
+    "smallvec",
+    "syn",
+    "thiserror",
+    "time",
+    "tokio",
+    "tracing",
+    "url",
+    "uuid",
+];
+
+impl ValidationMethods {
+    pub fn validate_npm_package_name(name: &str) -> bool {
+        if name.len() > NPM_NAME_MAX_LENGTH {
+            return false;
+        }
+
+        match name.strip_prefix('@') {
+            Some(scoped) => match scoped.split_once('/') {
+                Some((scope, name)) => is_npm_name_part(scope) && is_npm_name_part(name),
+                None => false,
+            },
+            None => is_npm_name_part(name) && NPM_RESERVED_NAMES.binary_search(&name).is_err(),
+        }
+    }
+
+    pub fn validate_pypi_package_name(name: &str) -> bool {
+        // PEP 508: alphanumeric at both ends, `.`, `_` and `-` in between.
+        name.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && name.ends_with(|c: char| c.is_ascii_alphanumeric())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+    }
+
+    pub fn validate_pypi_name_normalized(name: &str) -> bool {
+        Self::validate_pypi_package_name(name) && normalize_pypi_name(name) == name
+    }
+
+    pub fn validate_crate_name(name: &str) -> bool {
+        name.len() <= CRATE_NAME_MAX_LENGTH
+            && name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+            && RUST_KEYWORDS.binary_search(&name).is_err()
+    }
+
+    pub fn validate_crate_name_strict(name: &str) -> bool {
+        let lowercase = name.to_ascii_lowercase();
+        Self::validate_crate_name(name)
+            && (lowercase == name
+                || WELL_KNOWN_CRATES
+                    .binary_search(&lowercase.as_str())
+                    .is_err())
+    }
+
+    pub fn validate_conda_package_name(name: &str) -> bool {
+        name.len() <= CONDA_NAME_MAX_LENGTH
+            && name.starts_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit())
+            && name.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '.')
+            })
+            && !name.contains("--")
+            && !name.contains("__")
+    }
+
+    /// Validates the build string of a conda package or match spec, such as
+    /// `py39h6a678d5_0` in `numpy=1.21.2=py39h6a678d5_0`.
+    ///
+    /// `-` is rejected because it separates name, version and build in
+    /// distribution file names; `*` is accepted for match spec globs like `py39*`.
+    pub fn validate_conda_build_string(build: &str) -> bool {
+        !build.is_empty()
+            && build
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '+' | '*'))
+    }
+}
+
+/// Lowercases a PyPI project name and collapses each run of `-`, `_` and `.`
+/// into a single `_`, so `My.Package` and `my--package` compare equal.
+pub fn normalize_pypi_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    let mut in_separator_run = false;
+    for c in name.chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !in_separator_run {
+                normalized.push('_');
+            }
+            in_separator_run = true;
+        } else {
+            normalized.push(c.to_ascii_lowercase());
+            in_separator_run = false;
+        }
+    }
+    normalized
+}
+
+fn is_npm_name_part(part: &str) -> bool {
+    !part.is_empty()
+        && !part.starts_with(['.', '_'])
+        && part.chars().all(|c| {
+            c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '.' | '_' | '~')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_npm_reserved_names_sorted() {
+        assert!(NPM_RESERVED_NAMES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_rust_keywords_sorted() {
+        assert!(RUST_KEYWORDS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_well_known_crates_sorted() {
+        assert!(WELL_KNOWN_CRATES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_validate_npm_package_name() {
+        assert!(ValidationMethods::validate_npm_package_name("lodash"));
+        assert!(ValidationMethods::validate_npm_package_name("@babel/core"));
+        assert!(ValidationMethods::validate_npm_package_name("lodash.merge"));
+        assert!(ValidationMethods::validate_npm_package_name("left-pad"));
+        assert!(ValidationMethods::validate_npm_package_name("@types/node"));
+        assert!(ValidationMethods::validate_npm_package_name("@scope/fs"));
+        assert!(!ValidationMethods::validate_npm_package_name("UPPERCASE"));
+        assert!(!ValidationMethods::validate_npm_package_name("fs"));
+        assert!(!ValidationMethods::validate_npm_package_name(
+            "node_modules"
+        ));
+        assert!(!ValidationMethods::validate_npm_package_name(".hidden"));
+        assert!(!ValidationMethods::validate_npm_package_name("_private"));
+        assert!(!ValidationMethods::validate_npm_package_name("has space"));
+        assert!(!ValidationMethods::validate_npm_package_name("caf\u{e9}"));
+        assert!(!ValidationMethods::validate_npm_package_name("@babel"));
+        assert!(!ValidationMethods::validate_npm_package_name("@/core"));
+        assert!(!ValidationMethods::validate_npm_package_name("@babel/"));
+        assert!(!ValidationMethods::validate_npm_package_name("@Babel/core"));
+        assert!(!ValidationMethods::validate_npm_package_name(
+            "@babel/_core"
+        ));
+        assert!(!ValidationMethods::validate_npm_package_name("@a/b/c"));
+        assert!(!ValidationMethods::validate_npm_package_name(""));
+
+        let max_length = "a".repeat(NPM_NAME_MAX_LENGTH);
+        assert!(ValidationMethods::validate_npm_package_name(&max_length));
+        let too_long = format!("@scope/{}", "a".repeat(NPM_NAME_MAX_LENGTH));
+        assert!(!ValidationMethods::validate_npm_package_name(&too_long));
+    }
+
+    #[test]
+    fn test_validate_pypi_package_name() {
+        assert!(ValidationMethods::validate_pypi_package_name("My-Package"));
+        assert!(ValidationMethods::validate_pypi_package_name("my_package"));
+        assert!(ValidationMethods::validate_pypi_package_name(
+            "zope.interface"
+        ));
+        assert!(ValidationMethods::validate_pypi_package_name("Django"));
+        assert!(ValidationMethods::validate_pypi_package_name("a"));
+        assert!(!ValidationMethods::validate_pypi_package_name("-leading"));
+        assert!(!ValidationMethods::validate_pypi_package_name("trailing."));
+        assert!(!ValidationMethods::validate_pypi_package_name("has space"));
+        assert!(!ValidationMethods::validate_pypi_package_name("caf\u{e9}"));
+        assert!(!ValidationMethods::validate_pypi_package_name(""));
+    }
+
+    #[test]
+    fn test_normalize_pypi_name() {
+        assert_eq!(normalize_pypi_name("My-Package"), "my_package");
+        assert_eq!(normalize_pypi_name("zope.interface"), "zope_interface");
+        assert_eq!(normalize_pypi_name("a-_.b"), "a_b");
+        assert_eq!(normalize_pypi_name("my_package"), "my_package");
+    }
+
+    #[test]
+    fn test_validate_pypi_name_normalized() {
+        assert!(ValidationMethods::validate_pypi_name_normalized(
+            "my_package"
+        ));
+        assert!(ValidationMethods::validate_pypi_name_normalized("requests"));
+        assert!(!ValidationMethods::validate_pypi_name_normalized(
+            "My-Package"
+        ));
+        assert!(!ValidationMethods::validate_pypi_name_normalized(
+            "my__package"
+        ));
+        assert!(!ValidationMethods::validate_pypi_name_normalized(
+            "_my_package"
+        ));
+    }
+
+    #[test]
+    fn test_validate_crate_name() {
+        assert!(ValidationMethods::validate_crate_name("my-crate"));
+        assert!(ValidationMethods::validate_crate_name("_internal"));
+        assert!(ValidationMethods::validate_crate_name("serde_json"));
+        assert!(ValidationMethods::validate_crate_name("Serde"));
+        assert!(ValidationMethods::validate_crate_name("typeof-utils"));
+        assert!(!ValidationMethods::validate_crate_name("type"));
+        assert!(!ValidationMethods::validate_crate_name("Self"));
+        assert!(!ValidationMethods::validate_crate_name("async"));
+        assert!(!ValidationMethods::validate_crate_name("1password"));
+        assert!(!ValidationMethods::validate_crate_name("-leading"));
+        assert!(!ValidationMethods::validate_crate_name("my.crate"));
+        assert!(!ValidationMethods::validate_crate_name("my crate"));
+        assert!(!ValidationMethods::validate_crate_name(""));
+
+        let max_length = "a".repeat(CRATE_NAME_MAX_LENGTH);
+        assert!(ValidationMethods::validate_crate_name(&max_length));
+        let too_long = "a".repeat(CRATE_NAME_MAX_LENGTH + 1);
+        assert!(!ValidationMethods::validate_crate_name(&too_long));
+    }
+
+    #[test]
+    fn test_validate_crate_name_strict() {
+        assert!(ValidationMethods::validate_crate_name_strict("serde"));
+        assert!(ValidationMethods::validate_crate_name_strict("my-crate"));
+        assert!(ValidationMethods::validate_crate_name_strict("MyCrate"));
+        assert!(!ValidationMethods::validate_crate_name_strict("Serde"));
+        assert!(!ValidationMethods::validate_crate_name_strict("TOKIO"));
+        assert!(!ValidationMethods::validate_crate_name_strict("Cfg-If"));
+        assert!(!ValidationMethods::validate_crate_name_strict("type"));
+    }
+
+    #[test]
+    fn test_validate_conda_package_name() {
+        assert!(ValidationMethods::validate_conda_package_name("numpy"));
+        assert!(ValidationMethods::validate_conda_package_name(
+            "scikit-learn"
+        ));
+        assert!(ValidationMethods::validate_conda_package_name(
+            "python-dateutil"
+        ));
+        assert!(ValidationMethods::validate_conda_package_name(
+            "ruamel.yaml"
+        ));
+        assert!(ValidationMethods::validate_conda_package_name("r-base"));
+        assert!(ValidationMethods::validate_conda_package_name("7zip"));
+        assert!(!ValidationMethods::validate_conda_package_name(
+            "invalid_UPPERCASE"
+        ));
+        assert!(!ValidationMethods::validate_conda_package_name("-leading"));
+        assert!(!ValidationMethods::validate_conda_package_name(
+            "double--hyphen"
+        ));
+        assert!(!ValidationMethods::validate_conda_package_name(
+            "double__underscore"
+        ));
+        assert!(!ValidationMethods::validate_conda_package_name("has space"));
+        assert!(!ValidationMethods::validate_conda_package_name(""));
+
+        let too_long = "a".repeat(CONDA_NAME_MAX_LENGTH + 1);
+        assert!(!ValidationMethods::validate_conda_package_name(&too_long));
+    }
+
+    #[test]
+    fn test_validate_conda_build_string() {
+        assert!(ValidationMethods::validate_conda_build_string(
+            "py39h6a678d5_0"
+        ));
+        assert!(ValidationMethods::validate_conda_build_string(
+            "pyhd8ed1ab_0"
+        ));
+        assert!(ValidationMethods::validate_conda_build_string("0"));
+        assert!(ValidationMethods::validate_conda_build_string(
+            "cuda112_py39*"
+        ));
+        assert!(!ValidationMethods::validate_conda_build_string(
+            "py39-h6a678d5"
+        ));
+        assert!(!ValidationMethods::validate_conda_build_string("py 39"));
+        assert!(!ValidationMethods::validate_conda_build_string(""));
+    }
+}
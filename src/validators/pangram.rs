@@ -0,0 +1,49 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_pangram(text: &str) -> bool {
+        letter_counts(text).iter().all(|&count| count >= 1)
+    }
+
+    pub fn validate_perfect_pangram(text: &str) -> bool {
+        letter_counts(text).iter().all(|&count| count == 1)
+    }
+}
+
+fn letter_counts(text: &str) -> [usize; 26] {
+    let mut counts = [0; 26];
+    for c in text.chars().filter(char::is_ascii_alphabetic) {
+        counts[usize::from(c.to_ascii_lowercase() as u8 - b'a')] += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    const QUICK_BROWN_FOX: &str = "The quick brown fox jumps over the lazy dog";
+
+    #[test]
+    fn test_validate_pangram() {
+        assert!(ValidationMethods::validate_pangram(QUICK_BROWN_FOX));
+        assert!(ValidationMethods::validate_pangram(
+            "PACK MY BOX WITH FIVE DOZEN LIQUOR JUGS"
+        ));
+        assert!(!ValidationMethods::validate_pangram(
+            "The quick brown fox jumps over the dog"
+        ));
+        assert!(!ValidationMethods::validate_pangram(""));
+    }
+
+    #[test]
+    fn test_validate_perfect_pangram() {
+        assert!(ValidationMethods::validate_perfect_pangram(
+            "Mr. Jock, TV quiz PhD, bags few lynx"
+        ));
+        assert!(!ValidationMethods::validate_perfect_pangram(
+            QUICK_BROWN_FOX
+        ));
+        assert!(!ValidationMethods::validate_perfect_pangram("abc"));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::ValidationMethods;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::path::MAIN_SEPARATOR;
+
+impl ValidationMethods {
+    pub fn validate_path_no_traversal(path: &str) -> bool {
+        let decoded = percent_decode(path);
+
+        !decoded.contains("..")
+            && !decoded.starts_with('/')
+            && !decoded.starts_with('\\')
+            && !has_drive_prefix(&decoded)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn validate_relative_path(path: &str) -> bool {
+        !path
+            .split(MAIN_SEPARATOR)
+            .any(|component| component == "..")
+    }
+}
+
+fn has_drive_prefix(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = core::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(byte) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+    #[cfg(feature = "std")]
+    use std::path::MAIN_SEPARATOR;
+
+    #[test]
+    fn test_validate_path_no_traversal() {
+        assert!(ValidationMethods::validate_path_no_traversal(
+            "safe/file.txt"
+        ));
+        assert!(!ValidationMethods::validate_path_no_traversal(
+            "../etc/passwd"
+        ));
+        assert!(!ValidationMethods::validate_path_no_traversal("/absolute"));
+        assert!(!ValidationMethods::validate_path_no_traversal(
+            "\\windows\\system32"
+        ));
+        assert!(!ValidationMethods::validate_path_no_traversal(
+            "C:\\Windows"
+        ));
+        assert!(!ValidationMethods::validate_path_no_traversal(
+            "%2e%2e/etc/passwd"
+        ));
+        assert!(!ValidationMethods::validate_path_no_traversal(
+            "%2E%2E%2Fsecret"
+        ));
+        assert!(!ValidationMethods::validate_path_no_traversal(
+            "%2fabsolute"
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_validate_relative_path() {
+        let sep = MAIN_SEPARATOR;
+
+        assert!(ValidationMethods::validate_relative_path(&format!(
+            "safe{sep}file.txt"
+        )));
+        assert!(ValidationMethods::validate_relative_path("file..txt"));
+        assert!(!ValidationMethods::validate_relative_path(&format!(
+            "..{sep}etc{sep}passwd"
+        )));
+        assert!(!ValidationMethods::validate_relative_path(&format!(
+            "safe{sep}..{sep}..{sep}etc"
+        )));
+    }
+}
@@ -0,0 +1,51 @@
+use crate::ValidationMethods;
+use regex::Regex;
+
+impl ValidationMethods {
+    pub fn validate_regex_pattern(pattern: &str) -> bool {
+        Regex::new(pattern).is_ok()
+    }
+}
+
+pub struct RegexValidator {
+    regex: Regex,
+}
+
+impl RegexValidator {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            regex: Regex::new(pattern)?,
+        })
+    }
+
+    pub fn validate(&self, input: &str) -> bool {
+        self.regex.is_match(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_regex_pattern() {
+        assert!(ValidationMethods::validate_regex_pattern(r"^\d{3}-\d{4}$"));
+        assert!(ValidationMethods::validate_regex_pattern(""));
+        assert!(!ValidationMethods::validate_regex_pattern("(unclosed"));
+        assert!(!ValidationMethods::validate_regex_pattern("[a-"));
+        assert!(!ValidationMethods::validate_regex_pattern("*leading"));
+    }
+
+    #[test]
+    fn test_regex_validator() {
+        let pattern = r"^[A-Z]{2}\d{4}$";
+        assert!(ValidationMethods::validate_regex_pattern(pattern));
+
+        let validator = RegexValidator::new(pattern).unwrap();
+        assert!(validator.validate("AB1234"));
+        assert!(!validator.validate("ab1234"));
+        assert!(!validator.validate("AB123"));
+
+        assert!(RegexValidator::new("(unclosed").is_err());
+    }
+}
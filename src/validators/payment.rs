@@ -0,0 +1,169 @@
+use super::checksum::luhn;
+#[cfg(feature = "std")]
+use crate::SystemClock;
+use crate::{Clock, ValidationMethods};
+use alloc::vec::Vec;
+
+impl ValidationMethods {
+    #[cfg(feature = "std")]
+    pub fn validate_credit_card_expiry(expiry: &str) -> bool {
+        Self::validate_credit_card_expiry_with_clock(expiry, &SystemClock)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn validate_credit_card_expiry_future(expiry: &str) -> bool {
+        Self::validate_credit_card_expiry_future_with_clock(expiry, &SystemClock)
+    }
+
+    pub fn validate_credit_card_expiry_with_clock(expiry: &str, clock: &impl Clock) -> bool {
+        let today = clock.today();
+        parse_expiry(expiry).is_some_and(|expiry| expiry >= (today.year, today.month))
+    }
+
+    pub fn validate_credit_card_expiry_future_with_clock(expiry: &str, clock: &impl Clock) -> bool {
+        let today = clock.today();
+        let next_month = if today.month == 12 {
+            (today.year + 1, 1)
+        } else {
+            (today.year, today.month + 1)
+        };
+        parse_expiry(expiry).is_some_and(|expiry| expiry >= next_month)
+    }
+
+    /// Validates a Visa, Mastercard or American Express PAN, ignoring spaces
+    /// and dashes: the issuer prefix and length must match a known brand and
+    /// the Luhn check must pass. Mastercard's 2221–2720 range is accepted
+    /// alongside 51–55.
+    pub fn validate_pci_pan(pan: &str) -> bool {
+        let digits: Vec<u32> = match pan
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-'))
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<u32>>>()
+        {
+            Some(digits) if (13..=19).contains(&digits.len()) => digits,
+            _ => return false,
+        };
+
+        let prefix = digits[..4]
+            .iter()
+            .fold(0, |prefix, digit| prefix * 10 + digit);
+        let brand_length_is_valid = match prefix {
+            4000..=4999 => matches!(digits.len(), 13 | 16 | 19),
+            2221..=2720 | 5100..=5599 => digits.len() == 16,
+            3400..=3499 | 3700..=3799 => digits.len() == 15,
+            _ => false,
+        };
+
+        brand_length_is_valid && luhn(&digits)
+    }
+}
+
+fn parse_expiry(expiry: &str) -> Option<(i32, u32)> {
+    let (month, year) = expiry.trim().split_once('/')?;
+    let is_digits = |value: &str| value.chars().all(|c| c.is_ascii_digit());
+
+    if month.len() != 2 || !is_digits(month) || !is_digits(year) {
+        return None;
+    }
+
+    let month: u32 = month.parse().ok()?;
+    let year: i32 = match year.len() {
+        2 => 2000 + year.parse::<i32>().ok()?,
+        4 => year.parse().ok()?,
+        _ => return None,
+    };
+
+    (1..=12).contains(&month).then_some((year, month))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, FixedClock, ValidationMethods};
+
+    const CLOCK: FixedClock = FixedClock(Date {
+        year: 2025,
+        month: 6,
+        day: 15,
+    });
+
+    #[test]
+    fn test_validate_credit_card_expiry() {
+        assert!(ValidationMethods::validate_credit_card_expiry_with_clock(
+            "06/25", &CLOCK
+        ));
+        assert!(ValidationMethods::validate_credit_card_expiry_with_clock(
+            "07/25", &CLOCK
+        ));
+        assert!(ValidationMethods::validate_credit_card_expiry_with_clock(
+            "06/2025", &CLOCK
+        ));
+        assert!(ValidationMethods::validate_credit_card_expiry_with_clock(
+            "01/30", &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_credit_card_expiry_with_clock(
+            "05/25", &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_credit_card_expiry_with_clock(
+            "12/24", &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_credit_card_expiry_with_clock(
+            "13/25", &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_credit_card_expiry_with_clock(
+            "00/26", &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_credit_card_expiry_with_clock(
+            "6/25", &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_credit_card_expiry_with_clock(
+            "06/025", &CLOCK
+        ));
+        assert!(!ValidationMethods::validate_credit_card_expiry_with_clock(
+            "0625", &CLOCK
+        ));
+    }
+
+    #[test]
+    fn test_validate_credit_card_expiry_future() {
+        assert!(!ValidationMethods::validate_credit_card_expiry_future_with_clock("06/25", &CLOCK));
+        assert!(!ValidationMethods::validate_credit_card_expiry_future_with_clock("05/25", &CLOCK));
+        assert!(ValidationMethods::validate_credit_card_expiry_future_with_clock("07/25", &CLOCK));
+
+        let december = FixedClock(Date::new(2025, 12, 1));
+        assert!(
+            !ValidationMethods::validate_credit_card_expiry_future_with_clock("12/25", &december)
+        );
+        assert!(
+            ValidationMethods::validate_credit_card_expiry_future_with_clock("01/26", &december)
+        );
+    }
+
+    #[test]
+    fn test_validate_pci_pan() {
+        assert!(ValidationMethods::validate_pci_pan("4111111111111111"));
+        assert!(ValidationMethods::validate_pci_pan("4111 1111 1111 1111"));
+        assert!(ValidationMethods::validate_pci_pan("4222222222222"));
+        assert!(ValidationMethods::validate_pci_pan("5555-5555-5555-4444"));
+        assert!(ValidationMethods::validate_pci_pan("2223003122003222"));
+        assert!(ValidationMethods::validate_pci_pan("378282246310005"));
+        assert!(ValidationMethods::validate_pci_pan("371449635398431"));
+        assert!(!ValidationMethods::validate_pci_pan("4111111111111112"));
+        assert!(!ValidationMethods::validate_pci_pan("6011111111111117"));
+        assert!(!ValidationMethods::validate_pci_pan("5655555555554444"));
+        assert!(!ValidationMethods::validate_pci_pan("3782822463100056"));
+        assert!(!ValidationMethods::validate_pci_pan("411111111111"));
+        assert!(!ValidationMethods::validate_pci_pan("4111_1111_1111_1111"));
+        assert!(!ValidationMethods::validate_pci_pan(""));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_validate_credit_card_expiry_system_clock() {
+        assert!(ValidationMethods::validate_credit_card_expiry("12/2999"));
+        assert!(!ValidationMethods::validate_credit_card_expiry("01/20"));
+        assert!(!ValidationMethods::validate_credit_card_expiry_future(
+            "01/20"
+        ));
+    }
+}
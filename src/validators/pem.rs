@@ -0,0 +1,85 @@
+use crate::ValidationMethods;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+impl ValidationMethods {
+    pub fn validate_pem_block(pem: &str) -> bool {
+        pem_label(pem).is_some()
+    }
+
+    pub fn validate_pem_certificate(pem: &str) -> bool {
+        pem_label(pem) == Some("CERTIFICATE")
+    }
+}
+
+fn pem_label(pem: &str) -> Option<&str> {
+    let lines: Vec<&str> = pem.trim().lines().map(str::trim).collect();
+    if lines.len() < 3 {
+        return None;
+    }
+
+    let begin = lines[0]
+        .strip_prefix("-----BEGIN ")?
+        .strip_suffix("-----")?;
+    let end = lines[lines.len() - 1]
+        .strip_prefix("-----END ")?
+        .strip_suffix("-----")?;
+    if begin != end || !is_pem_label(begin) {
+        return None;
+    }
+
+    let body: String = lines[1..lines.len() - 1].concat();
+    ValidationMethods::validate_base64(&body).then_some(begin)
+}
+
+fn is_pem_label(label: &str) -> bool {
+    !label.is_empty()
+        && !label.starts_with(['-', ' '])
+        && !label.ends_with(['-', ' '])
+        && label.chars().all(|c| c.is_ascii_graphic() || c == ' ')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    const CERTIFICATE: &str = "-----BEGIN CERTIFICATE-----
+MIIDdzCCAl+gAwIBAgIEAgAAuTANBgkqhkiG9w0BAQUFADBaMQswCQYDVQQGEwJJ
+RTESMBAGA1UEChMJQmFsdGltb3Jl
+-----END CERTIFICATE-----";
+
+    const PUBLIC_KEY: &str = "-----BEGIN PUBLIC KEY-----
+MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEA
+-----END PUBLIC KEY-----
+";
+
+    #[test]
+    fn test_validate_pem_block() {
+        assert!(ValidationMethods::validate_pem_block(CERTIFICATE));
+        assert!(ValidationMethods::validate_pem_block(PUBLIC_KEY));
+        assert!(ValidationMethods::validate_pem_block(
+            &CERTIFICATE.replace('\n', "\r\n")
+        ));
+        assert!(!ValidationMethods::validate_pem_block(
+            "-----BEGIN CERTIFICATE-----\nMIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEA\n-----END PUBLIC KEY-----"
+        ));
+        assert!(!ValidationMethods::validate_pem_block(
+            "-----BEGIN CERTIFICATE-----\nnot base64!\n-----END CERTIFICATE-----"
+        ));
+        assert!(!ValidationMethods::validate_pem_block(
+            "-----BEGIN CERTIFICATE-----\n-----END CERTIFICATE-----"
+        ));
+        assert!(!ValidationMethods::validate_pem_block(
+            "-----BEGIN CERTIFICATE-----\nMIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEA"
+        ));
+        assert!(!ValidationMethods::validate_pem_block(
+            "MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEA"
+        ));
+    }
+
+    #[test]
+    fn test_validate_pem_certificate() {
+        assert!(ValidationMethods::validate_pem_certificate(CERTIFICATE));
+        assert!(!ValidationMethods::validate_pem_certificate(PUBLIC_KEY));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::ValidationMethods;
+use alloc::string::String;
+
+// (ISO 3166-1 alpha-2 code, calling code, national numbers drop a leading trunk `0`)
+const G20_CALLING_CODES: [(&str, &str, bool); 20] = [
+    ("AR", "54", true),
+    ("AU", "61", true),
+    ("BR", "55", true),
+    ("CA", "1", false),
+    ("CN", "86", true),
+    ("DE", "49", true),
+    ("FR", "33", true),
+    ("GB", "44", true),
+    ("ID", "62", true),
+    ("IN", "91", true),
+    ("IT", "39", false),
+    ("JP", "81", true),
+    ("KR", "82", true),
+    ("MX", "52", false),
+    ("RU", "7", false),
+    ("SA", "966", true),
+    ("TR", "90", true),
+    ("UK", "44", true),
+    ("US", "1", false),
+    ("ZA", "27", true),
+];
+
+pub struct PhoneValidator;
+
+impl PhoneValidator {
+    /// Returns a check that plugs into `Validator::with_validation`, or
+    /// `None` when `default_country` is not one of the G20 members in the
+    /// calling code table.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(default_country: &str) -> Option<impl Fn(&str) -> bool> {
+        let country = default_country.to_ascii_uppercase();
+        let index = G20_CALLING_CODES
+            .binary_search_by(|(code, _, _)| (*code).cmp(country.as_str()))
+            .ok()?;
+        let (_, calling_code, drops_trunk_prefix) = G20_CALLING_CODES[index];
+
+        Some(move |phone: &str| is_valid_with_calling_code(phone, calling_code, drops_trunk_prefix))
+    }
+}
+
+fn is_valid_with_calling_code(phone: &str, calling_code: &str, drops_trunk_prefix: bool) -> bool {
+    let phone: String = phone
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+        .collect();
+    if phone.starts_with('+') {
+        return ValidationMethods::validate_phone_e164(&phone);
+    }
+
+    let national = match phone.strip_prefix('0') {
+        Some(national) if drops_trunk_prefix => national,
+        _ => phone.as_str(),
+    };
+    let mut international = String::from("+");
+    international.push_str(calling_code);
+    international.push_str(national);
+    ValidationMethods::validate_phone_e164(&international)
+}
+
+impl ValidationMethods {
+    pub fn validate_phone_e164(phone: &str) -> bool {
+        match phone.strip_prefix('+') {
+            Some(digits) => {
+                (2..=15).contains(&digits.len())
+                    && !digits.starts_with('0')
+                    && digits.chars().all(|c| c.is_ascii_digit())
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_g20_calling_codes_sorted() {
+        assert!(G20_CALLING_CODES
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_validate_phone_e164() {
+        assert!(ValidationMethods::validate_phone_e164("+14155552671"));
+        assert!(ValidationMethods::validate_phone_e164("+447911123456"));
+        assert!(!ValidationMethods::validate_phone_e164("14155552671"));
+        assert!(!ValidationMethods::validate_phone_e164("+04155552671"));
+        assert!(!ValidationMethods::validate_phone_e164("+1415555267112345"));
+        assert!(!ValidationMethods::validate_phone_e164("+1 415 555 2671"));
+        assert!(!ValidationMethods::validate_phone_e164("+"));
+    }
+
+    #[test]
+    fn test_phone_validator_us() {
+        let validator = PhoneValidator::new("US").unwrap();
+        assert!(validator("4155552671"));
+        assert!(validator("(415) 555-2671"));
+        assert!(validator("+14155552671"));
+        assert!(!validator("415555267112345"));
+        assert!(!validator("415-CALL-NOW"));
+    }
+
+    #[test]
+    fn test_phone_validator_uk() {
+        let validator = PhoneValidator::new("gb").unwrap();
+        assert!(validator("07911 123456"));
+        assert!(validator("7911123456"));
+        assert!(validator("+44 7911 123456"));
+        assert!(PhoneValidator::new("UK").unwrap()("07911123456"));
+    }
+
+    #[test]
+    fn test_phone_validator_existing_prefix() {
+        let validator = PhoneValidator::new("DE").unwrap();
+        assert!(validator("+14155552671"));
+        assert!(validator("030 123456"));
+        assert!(!validator("+0301234567"));
+    }
+
+    #[test]
+    fn test_phone_validator_in_validator() {
+        let validator = crate::Validator::new(vec![ValidationMethods::not_empty])
+            .with_validation(PhoneValidator::new("US").unwrap());
+        assert!(validator.validate("415 555 2671"));
+        assert!(!validator.validate("415-CALL-NOW"));
+    }
+
+    #[test]
+    fn test_phone_validator_unsupported_country() {
+        assert!(PhoneValidator::new("NL").is_none());
+        assert!(PhoneValidator::new("XX").is_none());
+        assert!(PhoneValidator::new("").is_none());
+    }
+}
@@ -0,0 +1,92 @@
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const BUILTIN_PROFANITY_BLOCKLIST: &str = include_str!("../../data/profanity_blocklist.txt");
+
+pub struct ProfanityValidator {
+    blocked_words: BTreeSet<String>,
+    substring_matching: bool,
+}
+
+impl ProfanityValidator {
+    pub fn new(blocklist: &[&str]) -> Self {
+        Self {
+            blocked_words: blocklist
+                .iter()
+                .map(|word| word.trim().to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect(),
+            substring_matching: false,
+        }
+    }
+
+    pub fn with_builtin_blocklist() -> Self {
+        let words: Vec<&str> = BUILTIN_PROFANITY_BLOCKLIST
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+
+        Self::new(&words)
+    }
+
+    pub fn with_substring_matching(mut self) -> Self {
+        self.substring_matching = true;
+        self
+    }
+
+    pub fn validate(&self, input: &str) -> bool {
+        let input = input.to_lowercase();
+
+        !input
+            .split(|c: char| c.is_whitespace() || c.is_ascii_punctuation())
+            .filter(|word| !word.is_empty())
+            .any(|word| {
+                if self.substring_matching {
+                    self.blocked_words
+                        .iter()
+                        .any(|blocked| word.contains(blocked.as_str()))
+                } else {
+                    self.blocked_words.contains(word)
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profanity_validator() {
+        let validator = ProfanityValidator::new(&["darn", "Heck"]);
+        assert!(validator.validate("What a lovely day"));
+        assert!(!validator.validate("darn it"));
+        assert!(!validator.validate("Oh, HECK!"));
+        assert!(!validator.validate("well...darn"));
+        assert!(validator.validate("darnit"));
+        assert!(validator.validate(""));
+    }
+
+    #[test]
+    fn test_profanity_validator_substring_matching() {
+        let exact = ProfanityValidator::new(&["darn"]);
+        let substring = ProfanityValidator::new(&["darn"]).with_substring_matching();
+        assert!(exact.validate("darnit, undarnable"));
+        assert!(!substring.validate("darnit"));
+        assert!(!substring.validate("undarnable"));
+        assert!(substring.validate("What a lovely day"));
+    }
+
+    #[test]
+    fn test_profanity_validator_builtin_blocklist() {
+        let validator = ProfanityValidator::with_builtin_blocklist();
+        assert!(validator.validate("Have a nice day"));
+        assert!(!validator.validate("this is bullshit"));
+        assert!(validator.validate("Scunthorpe"));
+        assert!(!ProfanityValidator::with_builtin_blocklist()
+            .with_substring_matching()
+            .validate("Scunthorpe"));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_prometheus_metric_name(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_alphabetic() || matches!(c, '_' | ':'))
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | ':'))
+    }
+
+    pub fn validate_prometheus_label_name(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    pub fn validate_prometheus_label_value(_value: &str) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_prometheus_metric_name() {
+        assert!(ValidationMethods::validate_prometheus_metric_name(
+            "http_requests_total"
+        ));
+        assert!(ValidationMethods::validate_prometheus_metric_name(
+            "job:request_latency_seconds:mean5m"
+        ));
+        assert!(ValidationMethods::validate_prometheus_metric_name(
+            "__reserved"
+        ));
+        assert!(!ValidationMethods::validate_prometheus_metric_name(
+            "123invalid"
+        ));
+        assert!(!ValidationMethods::validate_prometheus_metric_name(
+            "http-requests"
+        ));
+        assert!(!ValidationMethods::validate_prometheus_metric_name(""));
+    }
+
+    #[test]
+    fn test_validate_prometheus_label_name() {
+        assert!(ValidationMethods::validate_prometheus_label_name("method"));
+        assert!(ValidationMethods::validate_prometheus_label_name(
+            "__reserved"
+        ));
+        assert!(!ValidationMethods::validate_prometheus_label_name(
+            "job:name"
+        ));
+        assert!(!ValidationMethods::validate_prometheus_label_name(
+            "123invalid"
+        ));
+        assert!(!ValidationMethods::validate_prometheus_label_name(""));
+    }
+
+    #[test]
+    fn test_validate_prometheus_label_value() {
+        assert!(ValidationMethods::validate_prometheus_label_value(""));
+        assert!(ValidationMethods::validate_prometheus_label_value(
+            "GET /api"
+        ));
+        assert!(ValidationMethods::validate_prometheus_label_value(
+            "żółw 🐢"
+        ));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_proto_field_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        chars.next().is_some_and(|c| c.is_ascii_lowercase())
+            && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    }
+
+    pub fn validate_proto_message_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        chars.next().is_some_and(|c| c.is_ascii_uppercase())
+            && chars.all(|c| c.is_ascii_alphanumeric())
+    }
+
+    pub fn validate_proto_package_name(name: &str) -> bool {
+        name.split('.').all(Self::validate_proto_field_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_proto_field_name() {
+        assert!(ValidationMethods::validate_proto_field_name("page_number"));
+        assert!(ValidationMethods::validate_proto_field_name(
+            "result_per_page"
+        ));
+        assert!(ValidationMethods::validate_proto_field_name("query"));
+        assert!(ValidationMethods::validate_proto_field_name("address2"));
+        assert!(!ValidationMethods::validate_proto_field_name("2address"));
+        assert!(!ValidationMethods::validate_proto_field_name("_private"));
+        assert!(!ValidationMethods::validate_proto_field_name("pageNumber"));
+        assert!(!ValidationMethods::validate_proto_field_name("PageNumber"));
+        assert!(!ValidationMethods::validate_proto_field_name("page-number"));
+        assert!(!ValidationMethods::validate_proto_field_name(""));
+    }
+
+    #[test]
+    fn test_validate_proto_message_name() {
+        assert!(ValidationMethods::validate_proto_message_name(
+            "SearchRequest"
+        ));
+        assert!(ValidationMethods::validate_proto_message_name(
+            "SongServerRequest"
+        ));
+        assert!(ValidationMethods::validate_proto_message_name("V2"));
+        assert!(!ValidationMethods::validate_proto_message_name(
+            "searchRequest"
+        ));
+        assert!(!ValidationMethods::validate_proto_message_name("2Request"));
+        assert!(!ValidationMethods::validate_proto_message_name(
+            "Search_Request"
+        ));
+        assert!(!ValidationMethods::validate_proto_message_name("_Search"));
+        assert!(!ValidationMethods::validate_proto_message_name(""));
+    }
+
+    #[test]
+    fn test_validate_proto_package_name() {
+        assert!(ValidationMethods::validate_proto_package_name(
+            "com.example.mypackage"
+        ));
+        assert!(ValidationMethods::validate_proto_package_name("foo.bar"));
+        assert!(ValidationMethods::validate_proto_package_name(
+            "google.protobuf"
+        ));
+        assert!(ValidationMethods::validate_proto_package_name("api_v1"));
+        assert!(!ValidationMethods::validate_proto_package_name(
+            "com.Example"
+        ));
+        assert!(!ValidationMethods::validate_proto_package_name(
+            "com..example"
+        ));
+        assert!(!ValidationMethods::validate_proto_package_name(
+            ".com.example"
+        ));
+        assert!(!ValidationMethods::validate_proto_package_name(
+            "com.example."
+        ));
+        assert!(!ValidationMethods::validate_proto_package_name(
+            "com.1example"
+        ));
+        assert!(!ValidationMethods::validate_proto_package_name(
+            "com-example"
+        ));
+        assert!(!ValidationMethods::validate_proto_package_name(""));
+    }
+}
@@ -0,0 +1,381 @@
+use crate::ValidationMethods;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::Peekable;
+use core::str::Chars;
+
+// `(.*a){11}` and beyond backtracks noticeably even though it is bounded.
+const MAX_WILDCARD_GROUP_REPEAT: u32 = 10;
+// Groups are parsed recursively, so untrusted input must not nest without
+// bound. Real patterns come nowhere near this.
+const MAX_GROUP_DEPTH: usize = 256;
+
+/// A pattern shape that backtracking regex engines can take exponential or
+/// polynomial time on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReDoSWarning {
+    /// The pattern could not be parsed (for example an unbalanced group, or
+    /// groups nested more than 256 deep).
+    Malformed,
+    /// A repeated group that itself contains a repetition: `(a+)+`,
+    /// `([a-zA-Z]+)*`.
+    NestedQuantifier,
+    /// A repeated group whose alternatives overlap or can match nothing:
+    /// `(a|aa)+`, `(a|a?)+`.
+    OverlappingAlternation,
+    /// Neighbouring repetitions of the same atom, or of `.`: `\d+\d+`, `.*.*`.
+    AdjacentQuantifiers,
+    /// A wildcard repetition inside a group repeated more than ten times:
+    /// `(.*a){11}`.
+    RepeatedWildcardGroup,
+}
+
+impl fmt::Display for ReDoSWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ReDoSWarning::Malformed => "pattern could not be parsed",
+            ReDoSWarning::NestedQuantifier => "nested quantifiers",
+            ReDoSWarning::OverlappingAlternation => "repeated group with overlapping alternatives",
+            ReDoSWarning::AdjacentQuantifiers => "adjacent quantifiers over overlapping atoms",
+            ReDoSWarning::RepeatedWildcardGroup => "wildcard inside a large repeated group",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReDoSWarning {}
+
+/// Heuristic static analysis for regular expressions accepted from users.
+///
+/// The `regex` crate itself runs in linear time, so this matters when a
+/// pattern may later be handed to a backtracking engine (PCRE, JavaScript,
+/// .NET). The check is purely syntactic and has known false negatives: it
+/// does not reason about character classes that overlap without being
+/// identical (`\w+\d+`), looks only at the first atom of each alternative,
+/// and ignores backreferences and lookarounds. It never compiles the pattern.
+pub struct ReDoSSafeValidator;
+
+impl ReDoSSafeValidator {
+    pub fn validate_pattern(pattern: &str) -> Result<(), ReDoSWarning> {
+        let mut chars = pattern.chars().peekable();
+        let alternatives = parse_alternatives(&mut chars, 0)?;
+        check_alternatives(&alternatives)
+    }
+}
+
+impl ValidationMethods {
+    pub fn validate_regex_no_catastrophic_backtracking(pattern: &str) -> bool {
+        ReDoSSafeValidator::validate_pattern(pattern).is_ok()
+    }
+}
+
+enum Atom {
+    // Source text of a literal, escape, class or anchor.
+    Single(String),
+    Group(Vec<Vec<Item>>),
+}
+
+struct Item {
+    atom: Atom,
+    min: u32,
+    max: Option<u32>,
+}
+
+impl Item {
+    fn is_unbounded(&self) -> bool {
+        self.max.is_none()
+    }
+
+    fn text(&self) -> Option<&str> {
+        match &self.atom {
+            Atom::Single(text) => Some(text),
+            Atom::Group(_) => None,
+        }
+    }
+}
+
+fn parse_alternatives(
+    chars: &mut Peekable<Chars>,
+    depth: usize,
+) -> Result<Vec<Vec<Item>>, ReDoSWarning> {
+    let in_group = depth > 0;
+    let mut alternatives = Vec::from([Vec::new()]);
+
+    while let Some(c) = chars.next() {
+        let atom = match c {
+            ')' if in_group => return Ok(alternatives),
+            '|' => {
+                alternatives.push(Vec::new());
+                continue;
+            }
+            '(' if depth == MAX_GROUP_DEPTH => return Err(ReDoSWarning::Malformed),
+            '(' => {
+                skip_group_prefix(chars);
+                Atom::Group(parse_alternatives(chars, depth + 1)?)
+            }
+            '[' => Atom::Single(parse_class(chars)?),
+            '\\' => Atom::Single(parse_escape(chars)?),
+            ')' | '*' | '+' | '?' => return Err(ReDoSWarning::Malformed),
+            c => Atom::Single(String::from(c)),
+        };
+        let (min, max) = parse_quantifier(chars)?;
+        if let Some(sequence) = alternatives.last_mut() {
+            sequence.push(Item { atom, min, max });
+        }
+    }
+
+    match in_group {
+        true => Err(ReDoSWarning::Malformed),
+        false => Ok(alternatives),
+    }
+}
+
+// Skips `?:`, `?P<name>`, `?<name>` and inline flags like `?i:` or `?i)`.
+fn skip_group_prefix(chars: &mut Peekable<Chars>) {
+    if chars.next_if_eq(&'?').is_none() {
+        return;
+    }
+    if chars.next_if(|&c| c == 'P' || c == '<').is_some() {
+        while chars.next_if(|&c| c != '>').is_some() {}
+        chars.next();
+        return;
+    }
+    while chars
+        .next_if(|&c| c.is_ascii_alphabetic() || c == '-')
+        .is_some()
+    {}
+    chars.next_if_eq(&':');
+}
+
+fn parse_class(chars: &mut Peekable<Chars>) -> Result<String, ReDoSWarning> {
+    let mut text = String::from('[');
+    let mut depth = 1;
+    // A `]` right after `[` or `[^` is a literal.
+    if let Some(c) = chars.next_if_eq(&'^') {
+        text.push(c);
+    }
+    if let Some(c) = chars.next_if_eq(&']') {
+        text.push(c);
+    }
+
+    while let Some(c) = chars.next() {
+        text.push(c);
+        match c {
+            '\\' => text.push(chars.next().ok_or(ReDoSWarning::Malformed)?),
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(text);
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(ReDoSWarning::Malformed)
+}
+
+fn parse_escape(chars: &mut Peekable<Chars>) -> Result<String, ReDoSWarning> {
+    let mut text = String::from('\\');
+    let c = chars.next().ok_or(ReDoSWarning::Malformed)?;
+    text.push(c);
+    // `\p{Greek}`, `\x{1F600}`
+    if matches!(c, 'p' | 'P' | 'x' | 'u') && chars.next_if_eq(&'{').is_some() {
+        text.push('{');
+        for c in chars.by_ref() {
+            text.push(c);
+            if c == '}' {
+                return Ok(text);
+            }
+        }
+        return Err(ReDoSWarning::Malformed);
+    }
+    Ok(text)
+}
+
+fn parse_quantifier(chars: &mut Peekable<Chars>) -> Result<(u32, Option<u32>), ReDoSWarning> {
+    let bounds = match chars.peek() {
+        Some('*') => (0, None),
+        Some('+') => (1, None),
+        Some('?') => (0, Some(1)),
+        Some('{') => {
+            chars.next();
+            let mut body = String::new();
+            for c in chars.by_ref() {
+                if c == '}' {
+                    break;
+                }
+                body.push(c);
+            }
+            let bounds = parse_repetition(&body).ok_or(ReDoSWarning::Malformed)?;
+            chars.next_if_eq(&'?');
+            return Ok(bounds);
+        }
+        _ => return Ok((1, Some(1))),
+    };
+    chars.next();
+    // Lazy quantifiers backtrack just the same.
+    chars.next_if_eq(&'?');
+    Ok(bounds)
+}
+
+fn parse_repetition(body: &str) -> Option<(u32, Option<u32>)> {
+    match body.split_once(',') {
+        Some((min, "")) => Some((min.trim().parse().ok()?, None)),
+        Some((min, max)) => Some((min.trim().parse().ok()?, Some(max.trim().parse().ok()?))),
+        None => {
+            let count = body.trim().parse().ok()?;
+            Some((count, Some(count)))
+        }
+    }
+}
+
+fn check_alternatives(alternatives: &[Vec<Item>]) -> Result<(), ReDoSWarning> {
+    for sequence in alternatives {
+        for pair in sequence.windows(2) {
+            if pair[0].is_unbounded()
+                && pair[1].is_unbounded()
+                && pair[0]
+                    .text()
+                    .zip(pair[1].text())
+                    .is_some_and(|(first, second)| first == second || first == "." || second == ".")
+            {
+                return Err(ReDoSWarning::AdjacentQuantifiers);
+            }
+        }
+
+        for item in sequence {
+            if let Atom::Group(inner) = &item.atom {
+                if item.is_unbounded() && contains_unbounded(inner) {
+                    return Err(ReDoSWarning::NestedQuantifier);
+                }
+                if item.max.is_none_or(|max| max > 1) && has_overlapping_alternatives(inner) {
+                    return Err(ReDoSWarning::OverlappingAlternation);
+                }
+                if item.min > MAX_WILDCARD_GROUP_REPEAT && contains_unbounded_wildcard(inner) {
+                    return Err(ReDoSWarning::RepeatedWildcardGroup);
+                }
+                check_alternatives(inner)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn contains_unbounded(alternatives: &[Vec<Item>]) -> bool {
+    alternatives.iter().flatten().any(|item| {
+        item.is_unbounded() || matches!(&item.atom, Atom::Group(inner) if contains_unbounded(inner))
+    })
+}
+
+fn contains_unbounded_wildcard(alternatives: &[Vec<Item>]) -> bool {
+    alternatives.iter().flatten().any(|item| match &item.atom {
+        Atom::Single(text) => text == "." && item.is_unbounded(),
+        Atom::Group(inner) => contains_unbounded_wildcard(inner),
+    })
+}
+
+fn has_overlapping_alternatives(alternatives: &[Vec<Item>]) -> bool {
+    if alternatives.len() < 2 {
+        return false;
+    }
+    let can_match_empty = |sequence: &Vec<Item>| sequence.iter().all(|item| item.min == 0);
+    let first_atoms: Vec<Option<&str>> = alternatives
+        .iter()
+        .map(|sequence| sequence.first().and_then(Item::text))
+        .collect();
+
+    alternatives.iter().any(can_match_empty)
+        || first_atoms
+            .iter()
+            .enumerate()
+            .any(|(index, first)| first.is_some() && first_atoms[index + 1..].contains(first))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_pattern_accepts_safe_patterns() {
+        for pattern in [
+            "",
+            "^[a-z0-9_]+$",
+            r"^\d{3}-\d{4}$",
+            "(ab)+",
+            "(a|b)+",
+            "(?:foo|bar)*baz",
+            r"^(\w+)\s(\w+)$",
+            "colou?r",
+            "[()+*]+",
+            r"\(a+\)+",
+            r"\p{Greek}+",
+            "(?P<year>[0-9]{4})-(?P<month>[0-9]{2})",
+            "(.*a){3}",
+            "a+b+",
+        ] {
+            assert_eq!(
+                ReDoSSafeValidator::validate_pattern(pattern),
+                Ok(()),
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_pattern_flags_vulnerable_patterns() {
+        let cases = [
+            ("(a+)+", ReDoSWarning::NestedQuantifier),
+            ("([a-zA-Z]+)*", ReDoSWarning::NestedQuantifier),
+            ("^(\\d+)*$", ReDoSWarning::NestedQuantifier),
+            ("((ab)*c)+", ReDoSWarning::NestedQuantifier),
+            ("(x{2,})+", ReDoSWarning::NestedQuantifier),
+            ("(a|aa)+", ReDoSWarning::OverlappingAlternation),
+            ("(a|a?)+", ReDoSWarning::OverlappingAlternation),
+            ("(foo|fob){2,5}", ReDoSWarning::OverlappingAlternation),
+            (r"\d+\d+", ReDoSWarning::AdjacentQuantifiers),
+            (".*.*=.*", ReDoSWarning::AdjacentQuantifiers),
+            ("a*?a+", ReDoSWarning::AdjacentQuantifiers),
+            ("(.*a){11}", ReDoSWarning::RepeatedWildcardGroup),
+            ("(a", ReDoSWarning::Malformed),
+            ("a)", ReDoSWarning::Malformed),
+            ("[a-z", ReDoSWarning::Malformed),
+            ("a{2,x}", ReDoSWarning::Malformed),
+            ("+a", ReDoSWarning::Malformed),
+        ];
+        for (pattern, warning) in cases {
+            assert_eq!(
+                ReDoSSafeValidator::validate_pattern(pattern),
+                Err(warning),
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_pattern_limits_group_depth() {
+        let nested = |depth: usize| "(".repeat(depth) + "a" + &")".repeat(depth);
+        assert_eq!(
+            ReDoSSafeValidator::validate_pattern(&nested(MAX_GROUP_DEPTH)),
+            Ok(())
+        );
+        assert_eq!(
+            ReDoSSafeValidator::validate_pattern(&nested(MAX_GROUP_DEPTH + 1)),
+            Err(ReDoSWarning::Malformed)
+        );
+        assert_eq!(
+            ReDoSSafeValidator::validate_pattern(&nested(20_000)),
+            Err(ReDoSWarning::Malformed)
+        );
+    }
+
+    #[test]
+    fn test_validate_regex_no_catastrophic_backtracking() {
+        assert!(ValidationMethods::validate_regex_no_catastrophic_backtracking("^[a-z]+$"));
+        assert!(!ValidationMethods::validate_regex_no_catastrophic_backtracking("(a+)+$"));
+    }
+}
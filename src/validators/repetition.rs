@@ -0,0 +1,51 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_no_repeated_characters(input: &str) -> bool {
+        max_consecutive_validator(1)(input)
+    }
+}
+
+pub fn max_consecutive_validator(max: usize) -> impl Fn(&str) -> bool {
+    move |input| {
+        let mut previous = None;
+        let mut run = 0;
+
+        input.chars().all(|c| {
+            run = if previous == Some(c) { run + 1 } else { 1 };
+            previous = Some(c);
+            run <= max
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_no_repeated_characters() {
+        assert!(ValidationMethods::validate_no_repeated_characters("valid"));
+        assert!(ValidationMethods::validate_no_repeated_characters("abab"));
+        assert!(ValidationMethods::validate_no_repeated_characters(""));
+        assert!(!ValidationMethods::validate_no_repeated_characters("hello"));
+        assert!(!ValidationMethods::validate_no_repeated_characters("aabb"));
+        assert!(!ValidationMethods::validate_no_repeated_characters(
+            "coffee"
+        ));
+        assert!(!ValidationMethods::validate_no_repeated_characters("żżółw"));
+    }
+
+    #[test]
+    fn test_max_consecutive_validator() {
+        let validator = max_consecutive_validator(2);
+        assert!(validator("hello"));
+        assert!(validator("aabbaa"));
+        assert!(!validator("aaaa"));
+        assert!(!validator("helllo"));
+
+        let validator = max_consecutive_validator(3);
+        assert!(validator("aaa"));
+        assert!(!validator("aaaa"));
+    }
+}
@@ -0,0 +1,145 @@
+use super::checksum::luhn;
+use super::country::is_iso_country_code;
+use crate::ValidationMethods;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const SEDOL_WEIGHTS: [u32; 7] = [1, 3, 1, 7, 3, 9, 1];
+
+// Prefixes ANNA assigns outside ISO 3166-1: Euroclear/Clearstream
+// international securities and EU-issued debt.
+const ISIN_EXTRA_PREFIXES: [&str; 2] = ["EU", "XS"];
+
+impl ValidationMethods {
+    pub fn validate_sedol(sedol: &str) -> bool {
+        if sedol.len() != 7 {
+            return false;
+        }
+
+        let mut sum = 0;
+        for (index, (c, weight)) in sedol.chars().zip(SEDOL_WEIGHTS).enumerate() {
+            let value = match c {
+                '0'..='9' => c.to_digit(10),
+                'A'..='Z' if index < 6 && !matches!(c, 'A' | 'E' | 'I' | 'O' | 'U') => {
+                    c.to_digit(36)
+                }
+                _ => None,
+            };
+            match value {
+                Some(value) => sum += value * weight,
+                None => return false,
+            }
+        }
+
+        sum.is_multiple_of(10)
+    }
+
+    pub fn validate_isin(isin: &str) -> bool {
+        if isin.len() != 12
+            || !isin
+                .chars()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+        {
+            return false;
+        }
+
+        let (body, check) = isin.split_at(11);
+        let country = &isin[..2];
+        (is_iso_country_code(country) || ISIN_EXTRA_PREFIXES.contains(&country))
+            && isin_check_digit(body).is_some_and(|digit| check.parse() == Ok(digit))
+    }
+}
+
+pub fn sedol_to_isin(sedol: &str, country: &str) -> Option<String> {
+    if !ValidationMethods::validate_sedol(sedol) || !is_iso_country_code(country) {
+        return None;
+    }
+
+    let body = format!("{}00{}", country, sedol);
+    let check = isin_check_digit(&body)?;
+    Some(format!("{}{}", body, check))
+}
+
+// Expands letters to their base-36 values (A = 10) and picks the digit that
+// makes the Luhn check pass over the whole ISIN.
+fn isin_check_digit(body: &str) -> Option<u32> {
+    let mut digits: Vec<u32> = Vec::with_capacity(body.len() * 2 + 1);
+    for c in body.chars() {
+        match c.to_digit(36) {
+            Some(value) if !c.is_ascii_lowercase() && value >= 10 => {
+                digits.extend([value / 10, value % 10])
+            }
+            Some(value) if !c.is_ascii_lowercase() => digits.push(value),
+            _ => return None,
+        }
+    }
+
+    for check in 0..10 {
+        digits.push(check);
+        if luhn(&digits) {
+            return Some(check);
+        }
+        digits.pop();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_sedol() {
+        assert!(ValidationMethods::validate_sedol("0263494"));
+        assert!(ValidationMethods::validate_sedol("0798059"));
+        assert!(ValidationMethods::validate_sedol("B0YBKJ7"));
+        assert!(ValidationMethods::validate_sedol("B0YBLH2"));
+        assert!(ValidationMethods::validate_sedol("2046251"));
+        assert!(!ValidationMethods::validate_sedol("0263495"));
+        assert!(!ValidationMethods::validate_sedol("B0YBKJ8"));
+        assert!(!ValidationMethods::validate_sedol("A0YBKJ7"));
+        assert!(!ValidationMethods::validate_sedol("b0ybkj7"));
+        assert!(!ValidationMethods::validate_sedol("B0YBKJB"));
+        assert!(!ValidationMethods::validate_sedol("026349"));
+        assert!(!ValidationMethods::validate_sedol("02634940"));
+        assert!(!ValidationMethods::validate_sedol(""));
+    }
+
+    #[test]
+    fn test_validate_isin() {
+        assert!(ValidationMethods::validate_isin("US0378331005"));
+        assert!(ValidationMethods::validate_isin("GB0005405286"));
+        assert!(ValidationMethods::validate_isin("US78462F1030"));
+        assert!(ValidationMethods::validate_isin("IE00B4L5Y983"));
+        assert!(ValidationMethods::validate_isin("US5949181045"));
+        assert!(ValidationMethods::validate_isin("XS2314659447"));
+        assert!(!ValidationMethods::validate_isin("US0378331006"));
+        assert!(!ValidationMethods::validate_isin("US0378331004"));
+        assert!(!ValidationMethods::validate_isin("UK0005405286"));
+        assert!(!ValidationMethods::validate_isin("us0378331005"));
+        assert!(!ValidationMethods::validate_isin("US037833100A"));
+        assert!(!ValidationMethods::validate_isin("US037833100"));
+        assert!(!ValidationMethods::validate_isin("US03783310055"));
+        assert!(!ValidationMethods::validate_isin(""));
+    }
+
+    #[test]
+    fn test_sedol_to_isin() {
+        assert_eq!(
+            sedol_to_isin("0263494", "GB").as_deref(),
+            Some("GB0002634946")
+        );
+        assert_eq!(
+            sedol_to_isin("0540528", "GB").as_deref(),
+            Some("GB0005405286")
+        );
+        assert_eq!(sedol_to_isin("0263495", "GB"), None);
+        assert_eq!(sedol_to_isin("0263494", "UK"), None);
+        assert_eq!(sedol_to_isin("0263494", "gb"), None);
+        for sedol in ["0263494", "B0YBKJ7"] {
+            let isin = sedol_to_isin(sedol, "GB").unwrap();
+            assert!(ValidationMethods::validate_isin(&isin));
+        }
+    }
+}
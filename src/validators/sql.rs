@@ -0,0 +1,247 @@
+use crate::ValidationMethods;
+use alloc::format;
+use alloc::string::ToString;
+
+const MAX_IDENTIFIER_LENGTH: usize = 128;
+
+const SQL_RESERVED_WORDS: [&str; 151] = [
+    "ADD",
+    "ALL",
+    "ALTER",
+    "AND",
+    "ANY",
+    "AS",
+    "ASC",
+    "AUTHORIZATION",
+    "BEGIN",
+    "BETWEEN",
+    "BIGINT",
+    "BINARY",
+    "BLOB",
+    "BOOLEAN",
+    "BOTH",
+    "BY",
+    "CALL",
+    "CASCADE",
+    "CASE",
+    "CAST",
+    "CHAR",
+    "CHARACTER",
+    "CHECK",
+    "CLOSE",
+    "COLLATE",
+    "COLUMN",
+    "COMMIT",
+    "CONNECT",
+    "CONSTRAINT",
+    "CONTINUE",
+    "CONVERT",
+    "CREATE",
+    "CROSS",
+    "CURRENT",
+    "CURRENT_DATE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "CURSOR",
+    "DATABASE",
+    "DATE",
+    "DAY",
+    "DEALLOCATE",
+    "DEC",
+    "DECIMAL",
+    "DECLARE",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DESCRIBE",
+    "DISTINCT",
+    "DOUBLE",
+    "DROP",
+    "ELSE",
+    "END",
+    "ESCAPE",
+    "EXCEPT",
+    "EXEC",
+    "EXECUTE",
+    "EXISTS",
+    "EXTERNAL",
+    "FALSE",
+    "FETCH",
+    "FLOAT",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "FULL",
+    "FUNCTION",
+    "GET",
+    "GLOBAL",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "HOUR",
+    "IDENTITY",
+    "IF",
+    "IN",
+    "INDEX",
+    "INNER",
+    "INSERT",
+    "INT",
+    "INTEGER",
+    "INTERSECT",
+    "INTERVAL",
+    "INTO",
+    "IS",
+    "JOIN",
+    "KEY",
+    "LEADING",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "LOCAL",
+    "MERGE",
+    "MINUTE",
+    "MONTH",
+    "NATURAL",
+    "NOT",
+    "NULL",
+    "NUMERIC",
+    "OF",
+    "OFFSET",
+    "ON",
+    "OPEN",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVER",
+    "PARTITION",
+    "PRIMARY",
+    "PROCEDURE",
+    "REAL",
+    "REFERENCES",
+    "RETURN",
+    "REVOKE",
+    "RIGHT",
+    "ROLLBACK",
+    "ROW",
+    "ROWS",
+    "SCHEMA",
+    "SECOND",
+    "SELECT",
+    "SESSION_USER",
+    "SET",
+    "SMALLINT",
+    "SOME",
+    "TABLE",
+    "THEN",
+    "TIME",
+    "TIMESTAMP",
+    "TO",
+    "TRAILING",
+    "TRANSACTION",
+    "TRIGGER",
+    "TRUE",
+    "TRUNCATE",
+    "UNION",
+    "UNIQUE",
+    "UNKNOWN",
+    "UPDATE",
+    "USER",
+    "USING",
+    "VALUES",
+    "VARCHAR",
+    "VIEW",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+    "YEAR",
+];
+
+impl ValidationMethods {
+    pub fn validate_sql_identifier(identifier: &str) -> bool {
+        let mut chars = identifier.chars();
+        let starts_correctly = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+
+        starts_correctly
+            && identifier.len() <= MAX_IDENTIFIER_LENGTH
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && SQL_RESERVED_WORDS
+                .binary_search(&identifier.to_ascii_uppercase().as_str())
+                .is_err()
+    }
+
+    pub fn validate_sql_identifier_quoted(identifier: &str) -> bool {
+        let quote = match identifier.chars().next() {
+            Some(quote @ ('"' | '`')) => quote,
+            _ => return false,
+        };
+        let inner = match identifier[1..].strip_suffix(quote) {
+            Some(inner) if !inner.is_empty() => inner,
+            _ => return false,
+        };
+
+        let doubled = format!("{quote}{quote}");
+        let unescaped = inner.replace(&doubled, "");
+
+        !unescaped.contains(quote)
+            && !inner.chars().any(|c| c.is_control())
+            && inner.replace(&doubled, &quote.to_string()).chars().count() <= MAX_IDENTIFIER_LENGTH
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sql_reserved_words_sorted() {
+        assert!(SQL_RESERVED_WORDS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_validate_sql_identifier() {
+        assert!(ValidationMethods::validate_sql_identifier("user_name"));
+        assert!(ValidationMethods::validate_sql_identifier("_private"));
+        assert!(ValidationMethods::validate_sql_identifier("Orders2024"));
+        assert!(ValidationMethods::validate_sql_identifier(&"a".repeat(128)));
+        assert!(!ValidationMethods::validate_sql_identifier("1table"));
+        assert!(!ValidationMethods::validate_sql_identifier("SELECT"));
+        assert!(!ValidationMethods::validate_sql_identifier("select"));
+        assert!(!ValidationMethods::validate_sql_identifier("user-name"));
+        assert!(!ValidationMethods::validate_sql_identifier("user name"));
+        assert!(!ValidationMethods::validate_sql_identifier(
+            &"a".repeat(129)
+        ));
+        assert!(!ValidationMethods::validate_sql_identifier(""));
+    }
+
+    #[test]
+    fn test_validate_sql_identifier_quoted() {
+        assert!(ValidationMethods::validate_sql_identifier_quoted(
+            "\"user name\""
+        ));
+        assert!(ValidationMethods::validate_sql_identifier_quoted("`order`"));
+        assert!(ValidationMethods::validate_sql_identifier_quoted(
+            "\"SELECT\""
+        ));
+        assert!(ValidationMethods::validate_sql_identifier_quoted(
+            "\"say \"\"hi\"\"\""
+        ));
+        assert!(!ValidationMethods::validate_sql_identifier_quoted("\"\""));
+        assert!(!ValidationMethods::validate_sql_identifier_quoted(
+            "\"unterminated"
+        ));
+        assert!(!ValidationMethods::validate_sql_identifier_quoted(
+            "`mixed\""
+        ));
+        assert!(!ValidationMethods::validate_sql_identifier_quoted(
+            "\"in\"jected\""
+        ));
+        assert!(!ValidationMethods::validate_sql_identifier_quoted(
+            "user_name"
+        ));
+    }
+}
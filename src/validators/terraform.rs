@@ -0,0 +1,172 @@
+use crate::ValidationMethods;
+
+const WORKSPACE_NAME_MAX_LENGTH: usize = 90;
+
+impl ValidationMethods {
+    pub fn validate_terraform_identifier(identifier: &str) -> bool {
+        identifier.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && identifier
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+    }
+
+    pub fn validate_terraform_module_name(name: &str) -> bool {
+        Self::validate_terraform_identifier(name)
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    }
+
+    pub fn validate_terraform_workspace_name(name: &str) -> bool {
+        (3..=WORKSPACE_NAME_MAX_LENGTH).contains(&name.len())
+            && !name.starts_with('-')
+            && !name.ends_with('-')
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+    }
+
+    pub fn validate_terraform_variable_name(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    pub fn validate_terraform_state_key(key: &str) -> bool {
+        let file_name = key.rsplit('/').next().unwrap_or_default();
+
+        Self::validate_path_no_traversal(key)
+            && file_name
+                .strip_suffix(".tfstate")
+                .is_some_and(|stem| !stem.is_empty())
+            && key
+                .split('/')
+                .all(|segment| !segment.is_empty() && segment != ".")
+            && !key.contains(|c: char| c.is_control() || c == '\\')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_terraform_identifier() {
+        assert!(ValidationMethods::validate_terraform_identifier(
+            "aws_instance"
+        ));
+        assert!(ValidationMethods::validate_terraform_identifier("web"));
+        assert!(ValidationMethods::validate_terraform_identifier("_private"));
+        assert!(ValidationMethods::validate_terraform_identifier(
+            "my-resource-2"
+        ));
+        assert!(ValidationMethods::validate_terraform_identifier("Example"));
+        assert!(!ValidationMethods::validate_terraform_identifier("2web"));
+        assert!(!ValidationMethods::validate_terraform_identifier("-web"));
+        assert!(!ValidationMethods::validate_terraform_identifier(
+            "aws.instance"
+        ));
+        assert!(!ValidationMethods::validate_terraform_identifier(
+            "my resource"
+        ));
+        assert!(!ValidationMethods::validate_terraform_identifier(""));
+    }
+
+    #[test]
+    fn test_validate_terraform_module_name() {
+        assert!(ValidationMethods::validate_terraform_module_name("consul"));
+        assert!(ValidationMethods::validate_terraform_module_name(
+            "vpc-peering"
+        ));
+        assert!(ValidationMethods::validate_terraform_module_name(
+            "s3-bucket2"
+        ));
+        assert!(!ValidationMethods::validate_terraform_module_name("VPC"));
+        assert!(!ValidationMethods::validate_terraform_module_name(
+            "vpc_peering"
+        ));
+        assert!(!ValidationMethods::validate_terraform_module_name("_vpc"));
+        assert!(!ValidationMethods::validate_terraform_module_name("9vpc"));
+    }
+
+    #[test]
+    fn test_validate_terraform_workspace_name() {
+        assert!(ValidationMethods::validate_terraform_workspace_name("prod"));
+        assert!(ValidationMethods::validate_terraform_workspace_name(
+            "networking_us-east-1"
+        ));
+        assert!(ValidationMethods::validate_terraform_workspace_name("_tmp"));
+        assert!(!ValidationMethods::validate_terraform_workspace_name("qa"));
+        assert!(!ValidationMethods::validate_terraform_workspace_name(
+            "-prod"
+        ));
+        assert!(!ValidationMethods::validate_terraform_workspace_name(
+            "prod-"
+        ));
+        assert!(!ValidationMethods::validate_terraform_workspace_name(
+            "prod.eu"
+        ));
+        assert!(!ValidationMethods::validate_terraform_workspace_name(
+            "my workspace"
+        ));
+
+        let max_length = "a".repeat(90);
+        assert!(ValidationMethods::validate_terraform_workspace_name(
+            &max_length
+        ));
+        let too_long = "a".repeat(91);
+        assert!(!ValidationMethods::validate_terraform_workspace_name(
+            &too_long
+        ));
+    }
+
+    #[test]
+    fn test_validate_terraform_variable_name() {
+        assert!(ValidationMethods::validate_terraform_variable_name(
+            "instance_type"
+        ));
+        assert!(ValidationMethods::validate_terraform_variable_name("AMI2"));
+        assert!(!ValidationMethods::validate_terraform_variable_name(
+            "_private"
+        ));
+        assert!(!ValidationMethods::validate_terraform_variable_name(
+            "2instances"
+        ));
+        assert!(!ValidationMethods::validate_terraform_variable_name(
+            "instance-type"
+        ));
+        assert!(!ValidationMethods::validate_terraform_variable_name(""));
+    }
+
+    #[test]
+    fn test_validate_terraform_state_key() {
+        assert!(ValidationMethods::validate_terraform_state_key(
+            "terraform.tfstate"
+        ));
+        assert!(ValidationMethods::validate_terraform_state_key(
+            "env/prod/network.tfstate"
+        ));
+        assert!(!ValidationMethods::validate_terraform_state_key(
+            "env/prod/network.json"
+        ));
+        assert!(!ValidationMethods::validate_terraform_state_key(".tfstate"));
+        assert!(!ValidationMethods::validate_terraform_state_key(
+            "env/.tfstate"
+        ));
+        assert!(!ValidationMethods::validate_terraform_state_key(
+            "/absolute/terraform.tfstate"
+        ));
+        assert!(!ValidationMethods::validate_terraform_state_key(
+            "env//terraform.tfstate"
+        ));
+        assert!(!ValidationMethods::validate_terraform_state_key(
+            "../terraform.tfstate"
+        ));
+        assert!(!ValidationMethods::validate_terraform_state_key(
+            "./terraform.tfstate"
+        ));
+        assert!(!ValidationMethods::validate_terraform_state_key(
+            "env\\terraform.tfstate"
+        ));
+        assert!(!ValidationMethods::validate_terraform_state_key(""));
+    }
+}
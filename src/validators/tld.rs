@@ -0,0 +1,73 @@
+use super::lookup::sorted_lines_contain;
+use crate::ValidationMethods;
+
+include!(concat!(env!("OUT_DIR"), "/tlds.rs"));
+
+const RESERVED_TLDS: [&str; 7] = [
+    "example",
+    "invalid",
+    "local",
+    "localdomain",
+    "localhost",
+    "onion",
+    "test",
+];
+
+impl ValidationMethods {
+    pub fn validate_tld(tld: &str) -> bool {
+        let tld = tld.strip_prefix('.').unwrap_or(tld).to_ascii_lowercase();
+        sorted_lines_contain(IANA_TLDS, &tld)
+    }
+
+    /// Accepts any IANA TLD plus any label that is syntactically a TLD (3–63
+    /// ASCII letters, not reserved), for domains under TLDs delegated after the
+    /// bundled list was generated. This does not check that the TLD exists.
+    pub fn validate_tld_syntax(tld: &str) -> bool {
+        let label = tld.strip_prefix('.').unwrap_or(tld).to_ascii_lowercase();
+
+        Self::validate_tld(&label)
+            || ((3..=63).contains(&label.len())
+                && label.chars().all(|c| c.is_ascii_lowercase())
+                && RESERVED_TLDS.binary_search(&label.as_str()).is_err())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tld_lists_sorted() {
+        let tlds: Vec<&str> = IANA_TLDS.lines().collect();
+        assert!(tlds.len() > 1000);
+        assert!(tlds.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(RESERVED_TLDS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_validate_tld() {
+        assert!(ValidationMethods::validate_tld(".com"));
+        assert!(ValidationMethods::validate_tld("com"));
+        assert!(ValidationMethods::validate_tld(".io"));
+        assert!(ValidationMethods::validate_tld(".museum"));
+        assert!(ValidationMethods::validate_tld(".DEV"));
+        assert!(ValidationMethods::validate_tld("xn--p1ai"));
+        assert!(!ValidationMethods::validate_tld(".invalid"));
+        assert!(!ValidationMethods::validate_tld(".localdomain"));
+        assert!(!ValidationMethods::validate_tld("..com"));
+        assert!(!ValidationMethods::validate_tld(""));
+    }
+
+    #[test]
+    fn test_validate_tld_syntax() {
+        assert!(ValidationMethods::validate_tld_syntax(".app"));
+        assert!(ValidationMethods::validate_tld_syntax(".dev"));
+        assert!(ValidationMethods::validate_tld_syntax(".newbrand"));
+        assert!(ValidationMethods::validate_tld_syntax("notarealtld"));
+        assert!(!ValidationMethods::validate_tld("notarealtld"));
+        assert!(!ValidationMethods::validate_tld_syntax(".invalid"));
+        assert!(!ValidationMethods::validate_tld_syntax(".localdomain"));
+        assert!(!ValidationMethods::validate_tld_syntax(".xy"));
+        assert!(!ValidationMethods::validate_tld_syntax(".c0m"));
+    }
+}
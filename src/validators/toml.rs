@@ -0,0 +1,46 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_toml_bare_key(key: &str) -> bool {
+        !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    }
+
+    pub fn validate_toml_dotted_key(key: &str) -> bool {
+        key.split('.').all(Self::validate_toml_bare_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_toml_bare_key() {
+        assert!(ValidationMethods::validate_toml_bare_key("my-key"));
+        assert!(ValidationMethods::validate_toml_bare_key("bare_key"));
+        assert!(ValidationMethods::validate_toml_bare_key("1234"));
+        assert!(!ValidationMethods::validate_toml_bare_key("invalid key"));
+        assert!(!ValidationMethods::validate_toml_bare_key("section.sub"));
+        assert!(!ValidationMethods::validate_toml_bare_key("ключ"));
+        assert!(!ValidationMethods::validate_toml_bare_key("\"quoted\""));
+        assert!(!ValidationMethods::validate_toml_bare_key(""));
+    }
+
+    #[test]
+    fn test_validate_toml_dotted_key() {
+        assert!(ValidationMethods::validate_toml_dotted_key("section.sub"));
+        assert!(ValidationMethods::validate_toml_dotted_key("a.b-c.d_e"));
+        assert!(ValidationMethods::validate_toml_dotted_key("my-key"));
+        assert!(!ValidationMethods::validate_toml_dotted_key("section. sub"));
+        assert!(!ValidationMethods::validate_toml_dotted_key(
+            "invalid key.sub"
+        ));
+        assert!(!ValidationMethods::validate_toml_dotted_key("section..sub"));
+        assert!(!ValidationMethods::validate_toml_dotted_key(".section"));
+        assert!(!ValidationMethods::validate_toml_dotted_key("section."));
+        assert!(!ValidationMethods::validate_toml_dotted_key(""));
+    }
+}
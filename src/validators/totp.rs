@@ -0,0 +1,81 @@
+use crate::ValidationMethods;
+
+pub struct TotpValidator {
+    digits: u8,
+    strict: bool,
+}
+
+impl TotpValidator {
+    /// Returns `None` unless `digits` is 6 or 8, the lengths RFC 6238
+    /// authenticators produce.
+    pub fn new(digits: u8) -> Option<Self> {
+        matches!(digits, 6 | 8).then_some(Self {
+            digits,
+            strict: false,
+        })
+    }
+
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    pub fn validate(&self, code: &str) -> bool {
+        code.len() == usize::from(self.digits)
+            && code.chars().all(|c| c.is_ascii_digit())
+            && !(self.strict && code.starts_with('0'))
+    }
+}
+
+impl ValidationMethods {
+    pub fn validate_totp_code(code: &str) -> bool {
+        [6, 8].into_iter().any(|digits| {
+            TotpValidator {
+                digits,
+                strict: false,
+            }
+            .validate(code)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_totp_code() {
+        assert!(ValidationMethods::validate_totp_code("123456"));
+        assert!(ValidationMethods::validate_totp_code("001234"));
+        assert!(ValidationMethods::validate_totp_code("12345678"));
+        assert!(!ValidationMethods::validate_totp_code("12345"));
+        assert!(!ValidationMethods::validate_totp_code("1234567"));
+        assert!(!ValidationMethods::validate_totp_code("12345a"));
+        assert!(!ValidationMethods::validate_totp_code(" 123456"));
+    }
+
+    #[test]
+    fn test_totp_validator() {
+        let six = TotpValidator::new(6).unwrap();
+        assert!(six.validate("123456"));
+        assert!(six.validate("001234"));
+        assert!(!six.validate("12345678"));
+        assert!(!six.validate("1234567"));
+
+        let eight = TotpValidator::new(8).unwrap();
+        assert!(eight.validate("12345678"));
+        assert!(!eight.validate("123456"));
+        assert!(!eight.validate("1234567"));
+
+        let strict = TotpValidator::new(6).unwrap().strict();
+        assert!(strict.validate("123456"));
+        assert!(!strict.validate("001234"));
+    }
+
+    #[test]
+    fn test_totp_validator_invalid_digits() {
+        assert!(TotpValidator::new(7).is_none());
+        assert!(TotpValidator::new(0).is_none());
+        assert!(TotpValidator::new(10).is_none());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::ValidationMethods;
+
+// (USPS code, full name, is one of the 50 states)
+const US_STATES: [(&str, &str, bool); 56] = [
+    ("AK", "Alaska", true),
+    ("AL", "Alabama", true),
+    ("AR", "Arkansas", true),
+    ("AS", "American Samoa", false),
+    ("AZ", "Arizona", true),
+    ("CA", "California", true),
+    ("CO", "Colorado", true),
+    ("CT", "Connecticut", true),
+    ("DC", "District of Columbia", false),
+    ("DE", "Delaware", true),
+    ("FL", "Florida", true),
+    ("GA", "Georgia", true),
+    ("GU", "Guam", false),
+    ("HI", "Hawaii", true),
+    ("IA", "Iowa", true),
+    ("ID", "Idaho", true),
+    ("IL", "Illinois", true),
+    ("IN", "Indiana", true),
+    ("KS", "Kansas", true),
+    ("KY", "Kentucky", true),
+    ("LA", "Louisiana", true),
+    ("MA", "Massachusetts", true),
+    ("MD", "Maryland", true),
+    ("ME", "Maine", true),
+    ("MI", "Michigan", true),
+    ("MN", "Minnesota", true),
+    ("MO", "Missouri", true),
+    ("MP", "Northern Mariana Islands", false),
+    ("MS", "Mississippi", true),
+    ("MT", "Montana", true),
+    ("NC", "North Carolina", true),
+    ("ND", "North Dakota", true),
+    ("NE", "Nebraska", true),
+    ("NH", "New Hampshire", true),
+    ("NJ", "New Jersey", true),
+    ("NM", "New Mexico", true),
+    ("NV", "Nevada", true),
+    ("NY", "New York", true),
+    ("OH", "Ohio", true),
+    ("OK", "Oklahoma", true),
+    ("OR", "Oregon", true),
+    ("PA", "Pennsylvania", true),
+    ("PR", "Puerto Rico", false),
+    ("RI", "Rhode Island", true),
+    ("SC", "South Carolina", true),
+    ("SD", "South Dakota", true),
+    ("TN", "Tennessee", true),
+    ("TX", "Texas", true),
+    ("UT", "Utah", true),
+    ("VA", "Virginia", true),
+    ("VI", "U.S. Virgin Islands", false),
+    ("VT", "Vermont", true),
+    ("WA", "Washington", true),
+    ("WI", "Wisconsin", true),
+    ("WV", "West Virginia", true),
+    ("WY", "Wyoming", true),
+];
+
+impl ValidationMethods {
+    pub fn validate_us_state_code(code: &str) -> bool {
+        find_us_state(code).is_some()
+    }
+
+    pub fn validate_us_state_only(code: &str) -> bool {
+        find_us_state(code).is_some_and(|(_, _, is_state)| is_state)
+    }
+}
+
+pub fn us_state_full_name(code: &str) -> Option<&'static str> {
+    find_us_state(code).map(|(_, name, _)| name)
+}
+
+fn find_us_state(code: &str) -> Option<(&'static str, &'static str, bool)> {
+    let code = code.to_ascii_uppercase();
+    US_STATES
+        .binary_search_by(|(candidate, _, _)| (*candidate).cmp(code.as_str()))
+        .ok()
+        .map(|index| US_STATES[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_us_states_sorted() {
+        assert!(US_STATES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_validate_us_state_code() {
+        assert!(ValidationMethods::validate_us_state_code("CA"));
+        assert!(ValidationMethods::validate_us_state_code("ca"));
+        assert!(ValidationMethods::validate_us_state_code("PR"));
+        assert!(ValidationMethods::validate_us_state_code("DC"));
+        assert!(!ValidationMethods::validate_us_state_code("XX"));
+        assert!(!ValidationMethods::validate_us_state_code("CAL"));
+        assert!(!ValidationMethods::validate_us_state_code(""));
+    }
+
+    #[test]
+    fn test_validate_us_state_only() {
+        assert!(ValidationMethods::validate_us_state_only("CA"));
+        assert!(ValidationMethods::validate_us_state_only("wy"));
+        assert!(!ValidationMethods::validate_us_state_only("PR"));
+        assert!(!ValidationMethods::validate_us_state_only("DC"));
+        assert!(!ValidationMethods::validate_us_state_only("XX"));
+    }
+
+    #[test]
+    fn test_us_state_full_name() {
+        assert_eq!(us_state_full_name("CA"), Some("California"));
+        assert_eq!(us_state_full_name("pr"), Some("Puerto Rico"));
+        assert_eq!(us_state_full_name("XX"), None);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::ValidationMethods;
+
+const UUID_GROUP_LENGTHS: [usize; 5] = [8, 4, 4, 4, 12];
+
+impl ValidationMethods {
+    pub fn validate_uuid(uuid: &str) -> bool {
+        let groups = uuid.split('-');
+
+        groups.clone().count() == UUID_GROUP_LENGTHS.len()
+            && groups.zip(UUID_GROUP_LENGTHS).all(|(group, length)| {
+                group.len() == length && group.chars().all(|c| c.is_ascii_hexdigit())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_uuid() {
+        assert!(ValidationMethods::validate_uuid(
+            "123e4567-e89b-12d3-a456-426614174000"
+        ));
+        assert!(ValidationMethods::validate_uuid(
+            "123E4567-E89B-12D3-A456-426614174000"
+        ));
+        assert!(ValidationMethods::validate_uuid(
+            "00000000-0000-0000-0000-000000000000"
+        ));
+        assert!(!ValidationMethods::validate_uuid(
+            "123e4567e89b12d3a456426614174000"
+        ));
+        assert!(!ValidationMethods::validate_uuid(
+            "123e4567-e89b-12d3-a456-42661417400"
+        ));
+        assert!(!ValidationMethods::validate_uuid(
+            "123e4567-e89b-12d3-a456-4266141740000"
+        ));
+        assert!(!ValidationMethods::validate_uuid(
+            "g23e4567-e89b-12d3-a456-426614174000"
+        ));
+        assert!(!ValidationMethods::validate_uuid(
+            "123e4567-e89b-12d3-a456-426614174000-"
+        ));
+    }
+}
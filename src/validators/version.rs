@@ -0,0 +1,169 @@
+use crate::ValidationMethods;
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+const OPERATORS: [&str; 7] = [">=", "<=", ">", "<", "=", "^", "~"];
+
+impl ValidationMethods {
+    pub fn validate_semver(version: &str) -> bool {
+        parse_version(version, false)
+    }
+
+    pub fn validate_version_range(range: &str) -> bool {
+        let range = range.trim();
+        !range.is_empty() && range.split("||").all(is_comparator_set)
+    }
+}
+
+fn is_comparator_set(set: &str) -> bool {
+    let mut tokens = set
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .peekable();
+    let mut comparators = 0;
+
+    while let Some(token) = tokens.next() {
+        let comparator = if OPERATORS.contains(&token) {
+            match tokens.next() {
+                Some(version) => format!("{token}{version}"),
+                None => return false,
+            }
+        } else {
+            token.to_string()
+        };
+
+        if !is_comparator(&comparator) {
+            return false;
+        }
+        comparators += 1;
+    }
+
+    comparators > 0
+}
+
+fn is_comparator(comparator: &str) -> bool {
+    if comparator == "*" {
+        return true;
+    }
+
+    let version = OPERATORS
+        .iter()
+        .find_map(|operator| comparator.strip_prefix(operator))
+        .unwrap_or(comparator);
+
+    parse_version(version, true)
+}
+
+fn parse_version(version: &str, allow_partial: bool) -> bool {
+    let (version, build) = match version.split_once('+') {
+        Some((version, build)) => (version, Some(build)),
+        None => (version, None),
+    };
+    let (core, pre_release) = match version.split_once('-') {
+        Some((core, pre_release)) => (core, Some(pre_release)),
+        None => (version, None),
+    };
+
+    let parts: Vec<&str> = core.split('.').collect();
+    let is_full = parts.len() == 3 && parts.iter().all(|part| is_numeric_identifier(part));
+
+    let core_is_valid = if allow_partial {
+        (1..=3).contains(&parts.len()) && is_partial_core(&parts)
+    } else {
+        is_full
+    };
+
+    core_is_valid
+        && (pre_release.is_none() && build.is_none() || is_full)
+        && pre_release.is_none_or(is_pre_release)
+        && build.is_none_or(is_build_metadata)
+}
+
+fn is_partial_core(parts: &[&str]) -> bool {
+    let first_wildcard = parts.iter().position(|part| is_wildcard(part));
+
+    match first_wildcard {
+        Some(index) => {
+            parts[..index]
+                .iter()
+                .all(|part| is_numeric_identifier(part))
+                && parts[index..].iter().all(|part| is_wildcard(part))
+        }
+        None => parts.iter().all(|part| is_numeric_identifier(part)),
+    }
+}
+
+fn is_wildcard(part: &str) -> bool {
+    matches!(part, "*" | "x" | "X")
+}
+
+fn is_numeric_identifier(part: &str) -> bool {
+    !part.is_empty()
+        && part.chars().all(|c| c.is_ascii_digit())
+        && (part == "0" || !part.starts_with('0'))
+}
+
+fn is_pre_release(pre_release: &str) -> bool {
+    pre_release.split('.').all(|identifier| {
+        is_alphanumeric_identifier(identifier)
+            && (!identifier.chars().all(|c| c.is_ascii_digit())
+                || is_numeric_identifier(identifier))
+    })
+}
+
+fn is_build_metadata(build: &str) -> bool {
+    build.split('.').all(is_alphanumeric_identifier)
+}
+
+fn is_alphanumeric_identifier(identifier: &str) -> bool {
+    !identifier.is_empty()
+        && identifier
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_semver() {
+        assert!(ValidationMethods::validate_semver("1.2.3"));
+        assert!(ValidationMethods::validate_semver("0.0.0"));
+        assert!(ValidationMethods::validate_semver("1.0.0-alpha.1"));
+        assert!(ValidationMethods::validate_semver("1.0.0-rc.1+build.5"));
+        assert!(ValidationMethods::validate_semver("1.0.0+20130313144700"));
+        assert!(!ValidationMethods::validate_semver("1.2"));
+        assert!(!ValidationMethods::validate_semver("01.2.3"));
+        assert!(!ValidationMethods::validate_semver("1.2.3-01"));
+        assert!(!ValidationMethods::validate_semver("1.2.3-"));
+        assert!(!ValidationMethods::validate_semver("1.2.3+"));
+        assert!(!ValidationMethods::validate_semver("v1.2.3"));
+        assert!(!ValidationMethods::validate_semver("1.2.*"));
+    }
+
+    #[test]
+    fn test_validate_version_range() {
+        assert!(ValidationMethods::validate_version_range("^1.2.3"));
+        assert!(ValidationMethods::validate_version_range("~0.5"));
+        assert!(ValidationMethods::validate_version_range(">=1.0.0 <2.0.0"));
+        assert!(ValidationMethods::validate_version_range(">=1.0.0, <2.0.0"));
+        assert!(ValidationMethods::validate_version_range(">= 1.2, < 1.5"));
+        assert!(ValidationMethods::validate_version_range("*"));
+        assert!(ValidationMethods::validate_version_range("1.*"));
+        assert!(ValidationMethods::validate_version_range("=1.0.0-beta.2"));
+        assert!(ValidationMethods::validate_version_range("1"));
+        assert!(ValidationMethods::validate_version_range("^1.2 || ^2.0"));
+
+        assert!(!ValidationMethods::validate_version_range(""));
+        assert!(!ValidationMethods::validate_version_range(">="));
+        assert!(!ValidationMethods::validate_version_range("^1.2.3.4"));
+        assert!(!ValidationMethods::validate_version_range("1.*.3"));
+        assert!(!ValidationMethods::validate_version_range("^01.2"));
+        assert!(!ValidationMethods::validate_version_range("^1.2-beta"));
+        assert!(!ValidationMethods::validate_version_range("=>1.0.0"));
+        assert!(!ValidationMethods::validate_version_range("latest"));
+        assert!(!ValidationMethods::validate_version_range("^1.2 ||"));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::ValidationMethods;
+
+const REGISTRY_HIVES: [(&str, &str); 5] = [
+    ("HKEY_CLASSES_ROOT", "HKCR"),
+    ("HKEY_CURRENT_CONFIG", "HKCC"),
+    ("HKEY_CURRENT_USER", "HKCU"),
+    ("HKEY_LOCAL_MACHINE", "HKLM"),
+    ("HKEY_USERS", "HKU"),
+];
+const REGISTRY_KEY_MAX_LENGTH: usize = 255;
+
+impl ValidationMethods {
+    pub fn validate_windows_registry_path(path: &str) -> bool {
+        let mut components = path.split('\\');
+        let hive_is_valid = components.next().is_some_and(|hive| {
+            REGISTRY_HIVES.iter().any(|(long, short)| {
+                hive.eq_ignore_ascii_case(long) || hive.eq_ignore_ascii_case(short)
+            })
+        });
+
+        hive_is_valid
+            && path.chars().count() <= REGISTRY_KEY_MAX_LENGTH
+            && components.all(|component| {
+                (1..=REGISTRY_KEY_MAX_LENGTH).contains(&component.chars().count())
+                    && !component.chars().any(char::is_control)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+    use alloc::format;
+
+    #[test]
+    fn test_validate_windows_registry_path() {
+        assert!(ValidationMethods::validate_windows_registry_path(
+            "HKEY_LOCAL_MACHINE\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion"
+        ));
+        assert!(ValidationMethods::validate_windows_registry_path(
+            "HKCU\\Software\\My App 2.0"
+        ));
+        assert!(ValidationMethods::validate_windows_registry_path(
+            "hkey_classes_root\\.txt"
+        ));
+        assert!(ValidationMethods::validate_windows_registry_path("HKU"));
+        assert!(ValidationMethods::validate_windows_registry_path(
+            "HKEY_CURRENT_CONFIG\\System"
+        ));
+        assert!(!ValidationMethods::validate_windows_registry_path(
+            "HKEY_LOCAL_MACHIN\\SOFTWARE"
+        ));
+        assert!(!ValidationMethods::validate_windows_registry_path(
+            "HKLM/SOFTWARE/Microsoft"
+        ));
+        assert!(!ValidationMethods::validate_windows_registry_path(
+            "\\HKLM\\SOFTWARE"
+        ));
+        assert!(!ValidationMethods::validate_windows_registry_path(
+            "HKLM\\SOFTWARE\\"
+        ));
+        assert!(!ValidationMethods::validate_windows_registry_path(
+            "HKLM\\\\SOFTWARE"
+        ));
+        assert!(!ValidationMethods::validate_windows_registry_path(
+            "HKLM\\SOFT\nWARE"
+        ));
+        assert!(!ValidationMethods::validate_windows_registry_path(
+            "SOFTWARE"
+        ));
+        assert!(!ValidationMethods::validate_windows_registry_path(""));
+    }
+
+    #[test]
+    fn test_validate_windows_registry_path_length() {
+        let longest = format!("HKLM\\{}", "k".repeat(250));
+        assert!(ValidationMethods::validate_windows_registry_path(&longest));
+        let overlong = format!("HKLM\\{}", "k".repeat(251));
+        assert!(!ValidationMethods::validate_windows_registry_path(
+            &overlong
+        ));
+        let overlong_component = format!("HKLM\\{}", "k".repeat(256));
+        assert!(!ValidationMethods::validate_windows_registry_path(
+            &overlong_component
+        ));
+    }
+}
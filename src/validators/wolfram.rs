@@ -0,0 +1,152 @@
+use crate::ValidationMethods;
+use alloc::vec::Vec;
+
+impl ValidationMethods {
+    /// Heuristic structural check for Wolfram Language input.
+    ///
+    /// Brackets must nest correctly (strings and `(* comments *)` are skipped),
+    /// `;;` may only appear as a span inside `[...]` such as `list[[2 ;; 4]]`,
+    /// and a name directly applied with `Name[...]` must start with a capital
+    /// letter or `$`. Part access such as `list[[1]]` is allowed on any name.
+    pub fn validate_wolfram_expression_basic(expression: &str) -> bool {
+        let trimmed = expression.trim();
+        if trimmed.is_empty() {
+            return false;
+        }
+
+        let chars: Vec<char> = trimmed.chars().collect();
+        let mut open = Vec::new();
+        let mut index = 0;
+        while index < chars.len() {
+            match chars[index] {
+                '"' => {
+                    index += 1;
+                    while index < chars.len() && chars[index] != '"' {
+                        index += if chars[index] == '\\' { 2 } else { 1 };
+                    }
+                    if index >= chars.len() {
+                        return false;
+                    }
+                }
+                '(' if chars.get(index + 1) == Some(&'*') => {
+                    match chars[index + 2..]
+                        .windows(2)
+                        .position(|pair| pair == ['*', ')'])
+                    {
+                        Some(end) => index += end + 3,
+                        None => return false,
+                    }
+                }
+                ';' if chars.get(index + 1) == Some(&';') => {
+                    if open.last() != Some(&'[') {
+                        return false;
+                    }
+                    index += 1;
+                }
+                c @ ('[' | '{' | '(') => open.push(c),
+                c @ (']' | '}' | ')') => {
+                    let expected = match c {
+                        ']' => '[',
+                        '}' => '{',
+                        _ => '(',
+                    };
+                    if open.pop() != Some(expected) {
+                        return false;
+                    }
+                }
+                c if c.is_alphabetic() || c == '$' => {
+                    let start = index;
+                    while chars
+                        .get(index + 1)
+                        .is_some_and(|&c| c.is_alphanumeric() || c == '$')
+                    {
+                        index += 1;
+                    }
+                    let is_call =
+                        chars.get(index + 1) == Some(&'[') && chars.get(index + 2) != Some(&'[');
+                    if is_call && !(chars[start].is_uppercase() || chars[start] == '$') {
+                        return false;
+                    }
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+
+        open.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_wolfram_expression_basic() {
+        assert!(ValidationMethods::validate_wolfram_expression_basic(
+            "Plot[Sin[x], {x, 0, 2*Pi}]"
+        ));
+        assert!(ValidationMethods::validate_wolfram_expression_basic(
+            "1 + 2"
+        ));
+        assert!(ValidationMethods::validate_wolfram_expression_basic(
+            "Integrate[x^2, {x, 0, 1}] (* area [ *)"
+        ));
+        assert!(ValidationMethods::validate_wolfram_expression_basic(
+            "StringJoin[\"a]\", \"b\\\"c\"]"
+        ));
+        assert!(ValidationMethods::validate_wolfram_expression_basic(
+            "list[[2 ;; 4]]"
+        ));
+        assert!(ValidationMethods::validate_wolfram_expression_basic(
+            "Take[list, 2;;4]"
+        ));
+        assert!(ValidationMethods::validate_wolfram_expression_basic(
+            "Print[\";;\"]"
+        ));
+        assert!(ValidationMethods::validate_wolfram_expression_basic(
+            "$Version"
+        ));
+        assert!(ValidationMethods::validate_wolfram_expression_basic(
+            "f = (a + b) c"
+        ));
+        assert!(ValidationMethods::validate_wolfram_expression_basic(
+            "Sqrt[2];"
+        ));
+    }
+
+    #[test]
+    fn test_validate_wolfram_expression_basic_rejects_malformed() {
+        assert!(!ValidationMethods::validate_wolfram_expression_basic(
+            "Plot[Sin[x"
+        ));
+        assert!(!ValidationMethods::validate_wolfram_expression_basic(
+            "lowercase[arg]"
+        ));
+        assert!(!ValidationMethods::validate_wolfram_expression_basic(
+            "Plot[Sin[x}]"
+        ));
+        assert!(!ValidationMethods::validate_wolfram_expression_basic(
+            "{1, 2]]"
+        ));
+        assert!(!ValidationMethods::validate_wolfram_expression_basic(
+            ";; 3"
+        ));
+        assert!(!ValidationMethods::validate_wolfram_expression_basic(
+            "f[x] ;;"
+        ));
+        assert!(!ValidationMethods::validate_wolfram_expression_basic(
+            "a;;b"
+        ));
+        assert!(!ValidationMethods::validate_wolfram_expression_basic(
+            "{1 ;; 2}"
+        ));
+        assert!(!ValidationMethods::validate_wolfram_expression_basic(
+            "Print[\"open]"
+        ));
+        assert!(!ValidationMethods::validate_wolfram_expression_basic(
+            "1 (* open"
+        ));
+        assert!(!ValidationMethods::validate_wolfram_expression_basic("   "));
+    }
+}
@@ -0,0 +1,215 @@
+use super::country::is_iso_country_code;
+use crate::ValidationMethods;
+use alloc::vec::Vec;
+
+// Attribute type short names from RFC 4514 section 3 plus the common
+// PKCS #9 and X.520 extras seen in certificate subjects.
+const DN_ATTRIBUTE_TYPES: [&str; 17] = [
+    "C",
+    "CN",
+    "DC",
+    "DNQUALIFIER",
+    "EMAILADDRESS",
+    "GENERATIONQUALIFIER",
+    "GIVENNAME",
+    "INITIALS",
+    "L",
+    "O",
+    "OU",
+    "SERIALNUMBER",
+    "SN",
+    "ST",
+    "STREET",
+    "TITLE",
+    "UID",
+];
+
+impl ValidationMethods {
+    /// Validates the RFC 4514 string form of an X.509 distinguished name,
+    /// such as `CN=example.com,O=Example Corp,C=US`.
+    ///
+    /// Attribute types must be a known short name or a dotted OID. Values may
+    /// use `\` escapes, the legacy `"..."` quoting, or `#` hex encoding; `C`
+    /// must be an ISO 3166-1 alpha-2 code.
+    pub fn validate_x509_subject_dn(dn: &str) -> bool {
+        let relative_names = match split_unescaped(dn, ',') {
+            Some(names) => names,
+            None => return false,
+        };
+
+        relative_names
+            .iter()
+            .all(|name| match split_unescaped(name, '+') {
+                Some(attributes) => attributes
+                    .iter()
+                    .all(|attribute| is_attribute_value_assertion(attribute.trim_start())),
+                None => false,
+            })
+    }
+}
+
+fn is_attribute_value_assertion(assertion: &str) -> bool {
+    let (attribute_type, value) = match assertion.split_once('=') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    let attribute_type = attribute_type.to_ascii_uppercase();
+
+    let type_is_valid = DN_ATTRIBUTE_TYPES
+        .binary_search(&attribute_type.as_str())
+        .is_ok()
+        || is_numeric_oid(&attribute_type);
+    let value_is_valid = match attribute_type.as_str() {
+        "C" => is_iso_country_code(value),
+        _ => is_dn_value(value),
+    };
+
+    type_is_valid && value_is_valid
+}
+
+fn is_numeric_oid(oid: &str) -> bool {
+    oid.split('.').count() > 1
+        && oid.split('.').all(|arc| {
+            !arc.is_empty()
+                && arc.chars().all(|c| c.is_ascii_digit())
+                && (arc == "0" || !arc.starts_with('0'))
+        })
+}
+
+fn is_dn_value(value: &str) -> bool {
+    if let Some(hex) = value.strip_prefix('#') {
+        return !hex.is_empty()
+            && hex.len().is_multiple_of(2)
+            && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+    if let Some(quoted) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        return is_escaped_text(quoted, "\"\\");
+    }
+
+    // Leading and trailing spaces are only kept when escaped.
+    !value.is_empty()
+        && !value.starts_with(' ')
+        && (!value.ends_with(' ') || value.ends_with("\\ "))
+        && is_escaped_text(value, "\"+,;<>\\")
+}
+
+// Checks that `special` characters only appear escaped, and that every `\`
+// escapes a special character, a space, `#`, `=` or a hex pair.
+fn is_escaped_text(text: &str, special: &str) -> bool {
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some(next) if "\"+,;<>\\ #=".contains(next) => {}
+                Some(next) if next.is_ascii_hexdigit() => {
+                    if chars.next_if(char::is_ascii_hexdigit).is_none() {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        } else if special.contains(c) {
+            return false;
+        }
+    }
+    true
+}
+
+// Splits on `separator` outside quotes and escapes; `None` if a quote or
+// escape is left open.
+fn split_unescaped(text: &str, separator: char) -> Option<Vec<&str>> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut chars = text.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next()?;
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == separator && !in_quotes => {
+                parts.push(&text[start..index]);
+                start = index + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    if in_quotes {
+        return None;
+    }
+    parts.push(&text[start..]);
+    Some(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dn_attribute_types_sorted() {
+        assert!(DN_ATTRIBUTE_TYPES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_validate_x509_subject_dn() {
+        assert!(ValidationMethods::validate_x509_subject_dn(
+            "CN=example.com,O=Example Corp,C=US"
+        ));
+        assert!(ValidationMethods::validate_x509_subject_dn(
+            "CN=Jane Doe,OU=Engineering,O=Example\\, Inc.,L=Warsaw,ST=Mazowieckie,C=PL"
+        ));
+        assert!(ValidationMethods::validate_x509_subject_dn(
+            "emailAddress=admin@example.com,CN=admin"
+        ));
+        assert!(ValidationMethods::validate_x509_subject_dn(
+            "CN=Steve Kille,O=Isode Limited,C=GB"
+        ));
+        assert!(ValidationMethods::validate_x509_subject_dn(
+            "OU=Sales+CN=J. Smith,DC=example,DC=net"
+        ));
+        assert!(ValidationMethods::validate_x509_subject_dn(
+            "CN=James \\\"Jim\\\" Smith\\, III,DC=example,DC=net"
+        ));
+        assert!(ValidationMethods::validate_x509_subject_dn(
+            "CN=Before\\0dAfter,DC=example,DC=net"
+        ));
+        assert!(ValidationMethods::validate_x509_subject_dn(
+            "1.3.6.1.4.1.1466.0=#04024869"
+        ));
+        assert!(ValidationMethods::validate_x509_subject_dn(
+            "CN=\"Example, Inc.\""
+        ));
+        assert!(ValidationMethods::validate_x509_subject_dn("CN=a, O=b"));
+    }
+
+    #[test]
+    fn test_validate_x509_subject_dn_rejects_malformed() {
+        assert!(!ValidationMethods::validate_x509_subject_dn(""));
+        assert!(!ValidationMethods::validate_x509_subject_dn("example.com"));
+        assert!(!ValidationMethods::validate_x509_subject_dn(
+            "CN=example.com,"
+        ));
+        assert!(!ValidationMethods::validate_x509_subject_dn(
+            "CN=example.com,,O=Example"
+        ));
+        assert!(!ValidationMethods::validate_x509_subject_dn("CN="));
+        assert!(!ValidationMethods::validate_x509_subject_dn("XYZ=value"));
+        assert!(!ValidationMethods::validate_x509_subject_dn("CN=a,C=USA"));
+        assert!(!ValidationMethods::validate_x509_subject_dn("CN=a,C=UK"));
+        assert!(!ValidationMethods::validate_x509_subject_dn("CN=a<b"));
+        assert!(!ValidationMethods::validate_x509_subject_dn("CN=a;b"));
+        assert!(!ValidationMethods::validate_x509_subject_dn(
+            "CN=trailing\\"
+        ));
+        assert!(!ValidationMethods::validate_x509_subject_dn("CN=bad\\q"));
+        assert!(!ValidationMethods::validate_x509_subject_dn("CN=bad\\0"));
+        assert!(!ValidationMethods::validate_x509_subject_dn(
+            "CN=\"unclosed"
+        ));
+        assert!(!ValidationMethods::validate_x509_subject_dn("CN= padded"));
+        assert!(!ValidationMethods::validate_x509_subject_dn("CN=#abc"));
+        assert!(!ValidationMethods::validate_x509_subject_dn("01.2=value"));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::ValidationMethods;
+
+impl ValidationMethods {
+    pub fn validate_xml_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        chars.next().is_some_and(is_name_start_char) && chars.all(is_name_char)
+    }
+
+    pub fn validate_xml_ncname(name: &str) -> bool {
+        !name.contains(':') && Self::validate_xml_name(name)
+    }
+}
+
+// NameStartChar production from XML 1.0 (Fifth Edition), section 2.3.
+fn is_name_start_char(c: char) -> bool {
+    matches!(c,
+        ':' | 'A'..='Z' | '_' | 'a'..='z'
+        | '\u{C0}'..='\u{D6}'
+        | '\u{D8}'..='\u{F6}'
+        | '\u{F8}'..='\u{2FF}'
+        | '\u{370}'..='\u{37D}'
+        | '\u{37F}'..='\u{1FFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}'
+        | '\u{2C00}'..='\u{2FEF}'
+        | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}'
+        | '\u{FDF0}'..='\u{FFFD}'
+        | '\u{10000}'..='\u{EFFFF}')
+}
+
+fn is_name_char(c: char) -> bool {
+    is_name_start_char(c)
+        || matches!(c,
+            '-' | '.' | '0'..='9' | '\u{B7}'
+            | '\u{300}'..='\u{36F}'
+            | '\u{203F}'..='\u{2040}')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_xml_name() {
+        assert!(ValidationMethods::validate_xml_name("my-element"));
+        assert!(ValidationMethods::validate_xml_name("ns:attr"));
+        assert!(ValidationMethods::validate_xml_name("valid_name"));
+        assert!(ValidationMethods::validate_xml_name("_private.v2"));
+        assert!(ValidationMethods::validate_xml_name("élément"));
+        assert!(ValidationMethods::validate_xml_name("名前"));
+        assert!(ValidationMethods::validate_xml_name("a\u{300}·b"));
+        assert!(!ValidationMethods::validate_xml_name("123invalid"));
+        assert!(!ValidationMethods::validate_xml_name("-start"));
+        assert!(!ValidationMethods::validate_xml_name(".start"));
+        assert!(!ValidationMethods::validate_xml_name("has space"));
+        assert!(!ValidationMethods::validate_xml_name("a×b"));
+        assert!(!ValidationMethods::validate_xml_name(""));
+    }
+
+    #[test]
+    fn test_validate_xml_ncname() {
+        assert!(ValidationMethods::validate_xml_ncname("my-element"));
+        assert!(ValidationMethods::validate_xml_ncname("valid_name"));
+        assert!(!ValidationMethods::validate_xml_ncname("ns:attr"));
+        assert!(!ValidationMethods::validate_xml_ncname(":attr"));
+        assert!(!ValidationMethods::validate_xml_ncname("123invalid"));
+    }
+}
@@ -0,0 +1,252 @@
+use crate::ValidationMethods;
+use alloc::string::String;
+
+// Schemes and CSS constructs that execute script when they reach an attribute
+// or stylesheet. Matched against a lowercased copy with whitespace removed.
+const SCRIPT_PATTERNS: [&str; 6] = [
+    "javascript:",
+    "vbscript:",
+    "livescript:",
+    "data:text/html",
+    "expression(",
+    "-moz-binding",
+];
+
+// `&amp;lt;script` needs two passes; a handful covers any realistic nesting.
+const MAX_DECODE_PASSES: usize = 4;
+
+impl ValidationMethods {
+    pub fn validate_no_html(input: &str) -> bool {
+        !input.contains(['<', '>'])
+    }
+}
+
+/// How aggressively [`XssValidator`] looks for script injection.
+///
+/// No mode is a substitute for context-aware output encoding; these checks
+/// reject obviously hostile input early, they do not make arbitrary input
+/// safe to render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XssMode {
+    /// Rejects `<` and `>`, like `validate_no_html`.
+    ///
+    /// Misses attribute injection (`" onerror="…`), `javascript:` URLs and
+    /// anything entity-encoded.
+    Basic,
+    /// `Basic` plus inline event handlers (`onload=`), script URL schemes
+    /// (`javascript:`, `vbscript:`, `data:text/html`) and CSS `expression(`.
+    ///
+    /// Still misses entity-encoded payloads such as `&lt;script&gt;` or
+    /// `&#106;avascript:`, and will reject harmless prose that happens to
+    /// contain `onfoo=`.
+    Standard,
+    /// `Standard` applied again after decoding HTML character references,
+    /// repeatedly, so `&lt;`, `&#x3C;` and double-encoded `&amp;lt;` are caught.
+    ///
+    /// Only the common named references are decoded; obscure named entities
+    /// and non-HTML encodings (URL, UTF-7, JavaScript escapes) pass through.
+    Strict,
+}
+
+pub struct XssValidator;
+
+impl XssValidator {
+    /// Returns a check for `mode` that plugs into `Validator::with_validation`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(mode: XssMode) -> impl Fn(&str) -> bool {
+        move |input| match mode {
+            XssMode::Basic => ValidationMethods::validate_no_html(input),
+            XssMode::Standard => is_standard_safe(input),
+            XssMode::Strict => is_strict_safe(input),
+        }
+    }
+}
+
+fn is_strict_safe(input: &str) -> bool {
+    if !is_standard_safe(input) {
+        return false;
+    }
+    let mut decoded = String::from(input);
+    for _ in 0..MAX_DECODE_PASSES {
+        let next = decode_entities(&decoded);
+        if next == decoded {
+            break;
+        }
+        if !is_standard_safe(&next) {
+            return false;
+        }
+        decoded = next;
+    }
+    true
+}
+
+fn is_standard_safe(input: &str) -> bool {
+    // Browsers ignore tabs and newlines inside URL schemes (`java\tscript:`).
+    let compact: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    ValidationMethods::validate_no_html(input)
+        && !SCRIPT_PATTERNS
+            .iter()
+            .any(|pattern| compact.contains(pattern))
+        && !has_event_handler(&input.to_ascii_lowercase())
+}
+
+// Looks for `on<letters>` at a word boundary followed by optional whitespace
+// and `=`, e.g. `onerror=` or `onload =`.
+fn has_event_handler(input: &str) -> bool {
+    let bytes = input.as_bytes();
+    input.match_indices("on").any(|(start, _)| {
+        if start > 0 && bytes[start - 1].is_ascii_alphanumeric() {
+            return false;
+        }
+        let rest = &bytes[start + 2..];
+        let name_len = rest.iter().take_while(|b| b.is_ascii_alphabetic()).count();
+        name_len > 0
+            && rest[name_len..]
+                .iter()
+                .find(|b| !b.is_ascii_whitespace())
+                .is_some_and(|&b| b == b'=')
+    })
+}
+
+fn decode_entities(input: &str) -> String {
+    let mut decoded = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match decode_entity(rest) {
+            Some((c, consumed)) => {
+                decoded.push(c);
+                rest = &rest[consumed..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+// Decodes one reference at the start of `input` (which begins with `&`),
+// returning the character and the number of bytes consumed. Numeric
+// references may omit the trailing `;`, as browsers allow.
+fn decode_entity(input: &str) -> Option<(char, usize)> {
+    let body = &input[1..];
+    if let Some(numeric) = body.strip_prefix('#') {
+        let (digits, radix, prefix_len) = match numeric.strip_prefix(['x', 'X']) {
+            Some(hex) => (hex, 16, 3),
+            None => (numeric, 10, 2),
+        };
+        let len = digits.chars().take_while(|c| c.is_digit(radix)).count();
+        if len == 0 {
+            return None;
+        }
+        let c = u32::from_str_radix(&digits[..len], radix)
+            .ok()
+            .and_then(char::from_u32)?;
+        let semicolon = usize::from(digits[len..].starts_with(';'));
+        return Some((c, prefix_len + len + semicolon));
+    }
+
+    let name_len = body.find(';')?;
+    let c = match body[..name_len].to_ascii_lowercase().as_str() {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "quot" => '"',
+        "apos" => '\'',
+        "colon" => ':',
+        "lpar" => '(',
+        "rpar" => ')',
+        "equals" => '=',
+        "tab" => '\t',
+        "newline" => '\n',
+        _ => return None,
+    };
+    Some((c, name_len + 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_no_html() {
+        assert!(ValidationMethods::validate_no_html("Hello, world"));
+        assert!(ValidationMethods::validate_no_html("1 + 1 = 2"));
+        assert!(!ValidationMethods::validate_no_html("<b>bold</b>"));
+        assert!(!ValidationMethods::validate_no_html("a > b"));
+    }
+
+    #[test]
+    fn test_xss_validator_basic() {
+        let validator = XssValidator::new(XssMode::Basic);
+        assert!(validator("plain text"));
+        assert!(!validator("<script>alert(1)</script>"));
+        assert!(validator("javascript:alert(1)"));
+        assert!(validator("&lt;script&gt;"));
+    }
+
+    #[test]
+    fn test_xss_validator_standard() {
+        let validator = XssValidator::new(XssMode::Standard);
+        assert!(validator("plain text"));
+        assert!(validator("Constantinople = Istanbul"));
+        assert!(validator("mention=@user"));
+        assert!(validator("turn it on = true"));
+        assert!(!validator("<img src=x>"));
+        assert!(!validator("javascript:alert(1)"));
+        assert!(!validator("JaVaScRiPt:alert(1)"));
+        assert!(!validator("java\tscript:alert(1)"));
+        assert!(!validator("\" onerror=\"alert(1)"));
+        assert!(!validator("x onMouseOver  = alert(1)"));
+        assert!(!validator("width: expression(alert(1))"));
+        assert!(!validator("data:text/html;base64,PHNjcmlwdD4="));
+        assert!(validator("&lt;script&gt;"));
+        assert!(validator("&#106;avascript:alert(1)"));
+    }
+
+    #[test]
+    fn test_xss_validator_strict() {
+        let validator = XssValidator::new(XssMode::Strict);
+        assert!(validator("plain text"));
+        assert!(validator("Fish &amp; chips"));
+        assert!(validator("AT&T"));
+        assert!(validator("&#169; 2024"));
+        assert!(!validator("<script>"));
+        assert!(!validator("&lt;script&gt;alert(1)"));
+        assert!(!validator("&LT;script"));
+        assert!(!validator("&#60;script"));
+        assert!(!validator("&#x3c;script"));
+        assert!(!validator("&amp;lt;script&amp;gt;"));
+        assert!(!validator("&#106;avascript:alert(1)"));
+        assert!(!validator("javascript&colon;alert(1)"));
+        assert!(!validator("x &#111;nerror=alert(1)"));
+    }
+
+    #[test]
+    fn test_xss_validator_in_validator_chain() {
+        let validator =
+            crate::Validator::new(vec![]).with_validation(XssValidator::new(XssMode::Strict));
+        assert!(validator.validate("plain text"));
+        assert!(!validator.validate("&lt;script&gt;"));
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("&lt;b&gt;"), "<b>");
+        assert_eq!(decode_entities("&#60;&#x3E;"), "<>");
+        assert_eq!(decode_entities("&#60"), "<");
+        assert_eq!(decode_entities("AT&T &unknown;"), "AT&T &unknown;");
+        assert_eq!(decode_entities("&#xFFFFFFFF;"), "&#xFFFFFFFF;");
+        assert_eq!(decode_entities("trailing &"), "trailing &");
+    }
+}
@@ -0,0 +1,81 @@
+use crate::ValidationMethods;
+
+const YAML_INDICATORS: &str = "{}[],:?#|>&*!%@`'\"";
+const YAML_RESERVED_WORDS: [&str; 4] = ["true", "false", "null", "~"];
+// A sequence entry (`- item`) and the document start and end markers.
+const YAML_LINE_PREFIXES: [&str; 3] = ["-", "---", "..."];
+
+impl ValidationMethods {
+    pub fn validate_yaml_bare_key(key: &str) -> bool {
+        !key.is_empty()
+            && key.trim() == key
+            && !key
+                .chars()
+                .any(|c| YAML_INDICATORS.contains(c) || c.is_control())
+            && !YAML_RESERVED_WORDS
+                .iter()
+                .any(|word| key.eq_ignore_ascii_case(word))
+            && !YAML_LINE_PREFIXES.iter().any(|prefix| {
+                key.strip_prefix(prefix)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+            })
+    }
+
+    pub fn validate_yaml_anchor_name(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| !c.is_whitespace() && !c.is_control() && !"[]{},".contains(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidationMethods;
+
+    #[test]
+    fn test_validate_yaml_bare_key() {
+        assert!(ValidationMethods::validate_yaml_bare_key("my_key"));
+        assert!(ValidationMethods::validate_yaml_bare_key("valid-key"));
+        assert!(ValidationMethods::validate_yaml_bare_key("with space"));
+        assert!(ValidationMethods::validate_yaml_bare_key("schlüssel"));
+        assert!(ValidationMethods::validate_yaml_bare_key("truthy"));
+        assert!(ValidationMethods::validate_yaml_bare_key("-negative"));
+        assert!(ValidationMethods::validate_yaml_bare_key("...etc"));
+        assert!(!ValidationMethods::validate_yaml_bare_key("true"));
+        assert!(!ValidationMethods::validate_yaml_bare_key("False"));
+        assert!(!ValidationMethods::validate_yaml_bare_key("NULL"));
+        assert!(!ValidationMethods::validate_yaml_bare_key("~"));
+        assert!(!ValidationMethods::validate_yaml_bare_key(
+            "key:with:colons"
+        ));
+        assert!(!ValidationMethods::validate_yaml_bare_key("#comment"));
+        assert!(!ValidationMethods::validate_yaml_bare_key("*alias"));
+        assert!(!ValidationMethods::validate_yaml_bare_key("[list]"));
+        assert!(!ValidationMethods::validate_yaml_bare_key("'quoted'"));
+        assert!(!ValidationMethods::validate_yaml_bare_key(" padded"));
+        for marker in ["- item", "-", "---", "...", "--- doc", "-\titem"] {
+            assert!(
+                !ValidationMethods::validate_yaml_bare_key(marker),
+                "{}",
+                marker
+            );
+        }
+        assert!(!ValidationMethods::validate_yaml_bare_key("line\nbreak"));
+        assert!(!ValidationMethods::validate_yaml_bare_key(""));
+    }
+
+    #[test]
+    fn test_validate_yaml_anchor_name() {
+        assert!(ValidationMethods::validate_yaml_anchor_name("defaults"));
+        assert!(ValidationMethods::validate_yaml_anchor_name(
+            "base-config_1"
+        ));
+        assert!(ValidationMethods::validate_yaml_anchor_name("a:b"));
+        assert!(!ValidationMethods::validate_yaml_anchor_name("two words"));
+        assert!(!ValidationMethods::validate_yaml_anchor_name("list[0]"));
+        assert!(!ValidationMethods::validate_yaml_anchor_name("a,b"));
+        assert!(!ValidationMethods::validate_yaml_anchor_name("{x}"));
+        assert!(!ValidationMethods::validate_yaml_anchor_name(""));
+    }
+}
@@ -0,0 +1,28 @@
+use crate::ValidationMethods;
+use wasm_bindgen::prelude::*;
+
+// Non-string arguments (`undefined`, numbers, objects) are reported as invalid
+// rather than throwing, so callers can pass raw form values straight through.
+fn validate_js_string(input: &JsValue, validator: fn(&str) -> bool) -> JsValue {
+    JsValue::from_bool(input.as_string().is_some_and(|input| validator(&input)))
+}
+
+#[wasm_bindgen(js_name = validateEmail, unchecked_return_type = "boolean")]
+pub fn validate_email(#[wasm_bindgen(unchecked_param_type = "string")] input: JsValue) -> JsValue {
+    validate_js_string(&input, ValidationMethods::validate_email)
+}
+
+#[wasm_bindgen(js_name = validateUrl, unchecked_return_type = "boolean")]
+pub fn validate_url(#[wasm_bindgen(unchecked_param_type = "string")] input: JsValue) -> JsValue {
+    validate_js_string(&input, ValidationMethods::validate_url)
+}
+
+#[wasm_bindgen(js_name = validateUuid, unchecked_return_type = "boolean")]
+pub fn validate_uuid(#[wasm_bindgen(unchecked_param_type = "string")] input: JsValue) -> JsValue {
+    validate_js_string(&input, ValidationMethods::validate_uuid)
+}
+
+#[wasm_bindgen(js_name = validateName, unchecked_return_type = "boolean")]
+pub fn validate_name(#[wasm_bindgen(unchecked_param_type = "string")] input: JsValue) -> JsValue {
+    validate_js_string(&input, ValidationMethods::validate_name)
+}
@@ -0,0 +1,47 @@
+pub fn min_words(min: usize) -> impl Fn(&str) -> bool {
+    move |input| word_count(input) >= min
+}
+
+pub fn max_words(max: usize) -> impl Fn(&str) -> bool {
+    move |input| word_count(input) <= max
+}
+
+pub fn word_count_between(min: usize, max: usize) -> impl Fn(&str) -> bool {
+    move |input| (min..=max).contains(&word_count(input))
+}
+
+fn word_count(input: &str) -> usize {
+    input.split_whitespace().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_words() {
+        let validator = min_words(3);
+        assert!(validator("hello world foo"));
+        assert!(validator("  hello   world \t foo\nbar "));
+        assert!(!validator("hello world"));
+        assert!(!validator("hello    world   "));
+        assert!(!validator(""));
+    }
+
+    #[test]
+    fn test_max_words() {
+        let validator = max_words(2);
+        assert!(validator("hello   world"));
+        assert!(validator(""));
+        assert!(!validator("hello world foo"));
+    }
+
+    #[test]
+    fn test_word_count_between() {
+        let validator = word_count_between(2, 3);
+        assert!(!validator("hello"));
+        assert!(validator("hello world"));
+        assert!(validator("hello  world foo"));
+        assert!(!validator("hello world foo bar"));
+    }
+}
@@ -0,0 +1,70 @@
+use rust_user_input_validation::{validator_factory, Form, FormBuilder};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+fn user_form() -> Form {
+    FormBuilder::new()
+        .field("name", validator_factory!(not_empty, validate_name))
+        .field("email", validator_factory!(not_empty, validate_email))
+        .build()
+}
+
+#[test]
+fn test_form_preserves_field_order() {
+    assert_eq!(user_form().field_names(), vec!["name", "email"]);
+}
+
+#[test]
+fn test_form_read_all_from() {
+    let mut cursor = Cursor::new(&b"John\njohn@example.com\n"[..]);
+
+    let values = user_form().read_all_from(&mut cursor).unwrap();
+    assert_eq!(values.len(), 2);
+    assert_eq!(values["name"], "John");
+    assert_eq!(values["email"], "john@example.com");
+}
+
+#[test]
+fn test_form_read_all_from_retries_invalid_fields() {
+    let mut cursor = Cursor::new(&b"John123\n\nJohn\ninvalid-email\njohn@example.com\n"[..]);
+
+    let values = user_form().read_all_from(&mut cursor).unwrap();
+    assert_eq!(values["name"], "John");
+    assert_eq!(values["email"], "john@example.com");
+}
+
+#[test]
+fn test_form_read_all_from_end_of_input() {
+    let mut cursor = Cursor::new(&b"John\ninvalid-email\n"[..]);
+
+    assert_eq!(user_form().read_all_from(&mut cursor), None);
+}
+
+#[test]
+fn test_form_validate_all_valid() {
+    let data = HashMap::from([("name", "John"), ("email", "john@example.com")]);
+
+    let report = user_form().validate_all(&data);
+    assert!(report.is_valid());
+    assert!(report.invalid_fields().is_empty());
+}
+
+#[test]
+fn test_form_validate_all_invalid() {
+    let data = HashMap::from([("name", "John123"), ("email", "john@example.com")]);
+
+    let report = user_form().validate_all(&data);
+    assert!(!report.is_valid());
+    assert_eq!(report.invalid_fields(), vec!["name"]);
+    assert_eq!(report.is_field_valid("name"), Some(false));
+    assert_eq!(report.is_field_valid("email"), Some(true));
+    assert_eq!(report.is_field_valid("age"), None);
+}
+
+#[test]
+fn test_form_validate_all_missing_field() {
+    let data = HashMap::from([("name", "John")]);
+
+    let report = user_form().validate_all(&data);
+    assert_eq!(report.invalid_fields(), vec!["email"]);
+}
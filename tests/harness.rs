@@ -0,0 +1,109 @@
+use rust_user_input_validation::{read_input_with_io, validator_factory, Validator};
+use std::io::Cursor;
+use std::str::FromStr;
+
+struct TestHarness {
+    input: Cursor<Vec<u8>>,
+    output: Vec<u8>,
+}
+
+impl TestHarness {
+    fn new(lines: Vec<&str>) -> Self {
+        let mut input = String::new();
+        for line in lines {
+            input.push_str(line);
+            input.push('\n');
+        }
+
+        Self {
+            input: Cursor::new(input.into_bytes()),
+            output: Vec::new(),
+        }
+    }
+
+    fn read_input<T>(&mut self, prompt: &str, validator: &Validator) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Debug,
+    {
+        read_input_with_io(prompt, validator, &mut self.input, &mut self.output)
+    }
+
+    fn output(&self) -> String {
+        String::from_utf8_lossy(&self.output).into_owned()
+    }
+
+    fn assert_output_contains(&self, expected: &str) {
+        let output = self.output();
+        assert!(
+            output.contains(expected),
+            "expected output to contain {:?}, got:\n{}",
+            expected,
+            output
+        );
+    }
+
+    fn assert_output_not_contains(&self, unexpected: &str) {
+        let output = self.output();
+        assert!(
+            !output.contains(unexpected),
+            "expected output not to contain {:?}, got:\n{}",
+            unexpected,
+            output
+        );
+    }
+}
+
+#[test]
+fn test_harness_single_field() {
+    let mut harness = TestHarness::new(vec!["John"]);
+
+    let name: Option<String> =
+        harness.read_input("Enter name:", &validator_factory!(not_empty, validate_name));
+    assert_eq!(name.as_deref(), Some("John"));
+    harness.assert_output_contains("Enter name:");
+    harness.assert_output_not_contains("Invalid input");
+}
+
+#[test]
+fn test_harness_user_registration() {
+    let mut harness = TestHarness::new(vec![
+        "John123",
+        "John",
+        "invalid-email",
+        "john@example.com",
+        "thirty",
+        "30",
+    ]);
+
+    let name: String = harness
+        .read_input("Enter name:", &validator_factory!(not_empty, validate_name))
+        .unwrap();
+    let email: String = harness
+        .read_input(
+            "Enter email:",
+            &validator_factory!(not_empty, validate_email),
+        )
+        .unwrap();
+    let age: u32 = harness
+        .read_input("Enter age:", &validator_factory!(not_empty))
+        .unwrap();
+
+    assert_eq!(
+        (name.as_str(), email.as_str(), age),
+        ("John", "john@example.com", 30)
+    );
+    harness.assert_output_contains("Enter email:");
+    harness.assert_output_contains("Invalid input, please try again.");
+    harness.assert_output_contains("Failed to convert value, please try again.");
+    assert_eq!(harness.output().matches("Enter name:").count(), 2);
+}
+
+#[test]
+fn test_harness_runs_out_of_input() {
+    let mut harness = TestHarness::new(vec!["John123"]);
+    let name: Option<String> =
+        harness.read_input("Enter name:", &validator_factory!(validate_name));
+    assert_eq!(name, None);
+    harness.assert_output_contains("Invalid input, please try again.");
+}
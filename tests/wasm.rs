@@ -0,0 +1,45 @@
+#![cfg(target_arch = "wasm32")]
+
+use rust_user_input_validation::wasm::{
+    validate_email, validate_name, validate_url, validate_uuid,
+};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn test_validate_email() {
+    assert_eq!(validate_email("test@example.com".into()), JsValue::TRUE);
+    assert_eq!(validate_email("invalid-email".into()), JsValue::FALSE);
+}
+
+#[wasm_bindgen_test]
+fn test_validate_url() {
+    assert_eq!(
+        validate_url("https://example.com/path".into()),
+        JsValue::TRUE
+    );
+    assert_eq!(validate_url("example.com".into()), JsValue::FALSE);
+}
+
+#[wasm_bindgen_test]
+fn test_validate_uuid() {
+    assert_eq!(
+        validate_uuid("123e4567-e89b-12d3-a456-426614174000".into()),
+        JsValue::TRUE
+    );
+    assert_eq!(validate_uuid("123e4567".into()), JsValue::FALSE);
+}
+
+#[wasm_bindgen_test]
+fn test_validate_name() {
+    assert_eq!(validate_name("John".into()), JsValue::TRUE);
+    assert_eq!(validate_name("John123".into()), JsValue::FALSE);
+}
+
+#[wasm_bindgen_test]
+fn test_non_string_input() {
+    assert_eq!(validate_email(JsValue::UNDEFINED), JsValue::FALSE);
+    assert_eq!(validate_name(JsValue::from_f64(42.0)), JsValue::FALSE);
+}